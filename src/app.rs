@@ -0,0 +1,611 @@
+use tui::
+{
+    backend::Backend,
+    widgets::{ListState, TableState},
+    Frame
+};
+use crossterm::event::{KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind};
+use tokio::sync::watch;
+use std::{io, path::PathBuf};
+
+use crate::weather::{Forecast, GeocodingResult, Horizon, Location, Units, WeatherError};
+use crate::settings::{Settings, SettingField, SettingsError};
+use crate::ui::{self, Screen, ScreenView};
+
+// everything the app reacts to, any of which redraws the screen
+pub enum Event
+{
+    Key(KeyEvent),
+    Mouse(MouseEvent),
+    // the new width and height, which the next draw picks up on its own
+    Resize(u16, u16),
+    Tick,
+    // the terminal can't be read from anymore, with why if it said
+    InputClosed(Option<io::Error>),
+    // a fetched forecast, along with what it was fetched for
+    DataLoaded(Fetch, Forecast),
+    FetchFailed(Fetch, WeatherError),
+    // the places found for a search query
+    SearchLoaded(String, Result<Vec<GeocodingResult>, WeatherError>)
+}
+
+impl Event
+{
+    pub fn fetched(fetch: Fetch, result: Result<Forecast, WeatherError>) -> Event
+    {
+        return match result
+        {
+            Ok(forecast) => Event::DataLoaded(fetch, forecast),
+            Err(e) => Event::FetchFailed(fetch, e)
+        };
+    }
+}
+
+// why a forecast was fetched, which decides what its result does
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FetchKind
+{
+    // the loading screen is waiting on it
+    Load,
+    // asked for from the forecast screen, with the spinner up until it is back
+    Refresh,
+    // the refresh task's regular update
+    Background
+}
+
+// a forecast request and the settings it was made with, so a result for settings that have since changed can be told apart
+#[derive(Clone, Debug, PartialEq)]
+pub struct Fetch
+{
+    pub kind: FetchKind,
+    pub location: Location,
+    pub units: Units,
+    pub horizon: Horizon
+}
+
+impl Fetch
+{
+    pub fn new(kind: FetchKind, settings: &Settings) -> Fetch
+    {
+        return Fetch
+        {
+            kind,
+            location: settings.location.clone(),
+            units: settings.units,
+            horizon: settings.horizon
+        };
+    }
+
+    // whether the result is for what the settings currently ask for
+    pub fn matches(&self, settings: &Settings) -> bool
+    {
+        return self.location == settings.location && self.units == settings.units && self.horizon == settings.horizon;
+    }
+}
+
+// what the main loop has to do after an event, for the work that can't happen while handling it
+#[derive(Debug, PartialEq)]
+pub enum Action
+{
+    None,
+    Quit,
+    // fetch in the background while the current forecast stays on screen
+    Refresh,
+    // fetch with the loading screen up, coming back to the screen it was started from
+    Fetch,
+    // look up the search query in the background
+    Search
+}
+
+// all of the state the screens share and keep between frames
+pub struct App
+{
+    pub settings: Settings,
+    // the settings as they are on disk, without this run's flags, which are the only ones written back
+    pub saved: Settings,
+    // tells the background refresh task about setting changes
+    pub settings_tx: watch::Sender<Settings>,
+    // where changed settings are saved, none when there is no config directory
+    pub config_path: Option<PathBuf>,
+    // set when the settings file couldn't be loaded, so it isn't overwritten with the defaults used instead
+    pub settings_error: Option<SettingsError>,
+    // why input stopped, reported once the terminal is restored
+    pub input_error: Option<io::Error>,
+    pub screen: Screen,
+    // where to go once the loading screen's forecast arrives
+    pub loading_from: Screen,
+    pub forecast: Forecast,
+    pub fetch_error: Option<WeatherError>,
+
+    // manual refreshes run alongside the ui so it keeps responding to input
+    pub refresh_failed: bool,
+    pub fetching: bool,
+    pub spinner_frame: usize,
+
+    // whether the forecast screen shows the charts instead of the days
+    pub show_chart: bool,
+
+    // which day is selected, the carousel and list scroll to keep it in view
+    pub day_index: usize,
+    pub day_state: TableState,
+
+    pub menu_state: ListState,
+    pub hourly_state: TableState,
+
+    pub search_query: String,
+    pub search_dirty: bool,
+    pub search_status: String,
+    pub search_results: Vec<GeocodingResult>,
+    pub search_state: ListState,
+
+    pub options_state: ListState,
+    pub options_status: String
+}
+
+impl App
+{
+    pub fn new(settings: Settings, saved: Settings, settings_tx: watch::Sender<Settings>, config_path: Option<PathBuf>) -> App
+    {
+        let mut menu_state: ListState = ListState::default();
+        menu_state.select(Some(0));
+
+        let mut hourly_state: TableState = TableState::default();
+        hourly_state.select(Some(0));
+
+        let mut options_state: ListState = ListState::default();
+        options_state.select(Some(0));
+
+        return App
+        {
+            settings,
+            saved,
+            settings_tx,
+            config_path,
+            settings_error: None,
+            input_error: None,
+            // nothing is shown until the first forecast arrives
+            screen: Screen::LOADING,
+            loading_from: Screen::FORECAST,
+            forecast: Forecast::default(),
+            fetch_error: None,
+            refresh_failed: false,
+            fetching: false,
+            spinner_frame: 0,
+            show_chart: false,
+            day_index: 0,
+            day_state: TableState::default(),
+            menu_state,
+            hourly_state,
+            search_query: String::new(),
+            search_dirty: true,
+            search_status: String::from("type a place and press enter"),
+            search_results: Vec::new(),
+            search_state: ListState::default(),
+            options_state,
+            options_status: String::new()
+        };
+    }
+
+    // draws the current screen over the theme's background
+    pub fn render<B: Backend>(&mut self, frame: &mut Frame<B>)
+    {
+        frame.render_widget(ui::get_background(&self.settings.theme), frame.size());
+
+        match self.screen
+        {
+            Screen::FORECAST => ui::forecast_screen::ForecastView.render(self, frame),
+            Screen::MENU => ui::menu_screen::MenuView.render(self, frame),
+            Screen::HOURLY => ui::hourly_screen::HourlyView.render(self, frame),
+            Screen::DAY => ui::day_screen::DayView.render(self, frame),
+            Screen::SEARCH => ui::search_screen::SearchView.render(self, frame),
+            Screen::ERROR => ui::error_screen::ErrorView.render(self, frame),
+            Screen::OPTIONS => ui::options_screen::OptionsView.render(self, frame),
+            Screen::LOADING => ui::loading_screen::LoadingView.render(self, frame)
+        }
+    }
+
+    // updates the state for an event, passing key presses on to the current screen
+    pub fn handle_event(&mut self, event: Event) -> Action
+    {
+        return match event
+        {
+            // raw mode delivers ctrl-c as a key press instead of a signal
+            Event::Key(key) if key.code == KeyCode::Char('c') && key.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+            Event::Key(key) => self.handle_key(key),
+            // scrolling moves through lists the same way the arrow keys do
+            Event::Mouse(mouse) => match mouse.kind
+            {
+                MouseEventKind::ScrollDown => self.handle_key(KeyEvent::new(KeyCode::Down, KeyModifiers::NONE)),
+                MouseEventKind::ScrollUp => self.handle_key(KeyEvent::new(KeyCode::Up, KeyModifiers::NONE)),
+                _ => Action::None
+            },
+            Event::Resize(_, _) => Action::None,
+            Event::Tick =>
+            {
+                self.spinner_frame = self.spinner_frame.wrapping_add(1);
+                Action::None
+            },
+            // nothing could be typed to quit with, so quitting right away
+            Event::InputClosed(error) =>
+            {
+                self.input_error = error;
+                Action::Quit
+            },
+            Event::DataLoaded(fetch, forecast) =>
+            {
+                self.apply_fetched(fetch, Ok(forecast));
+                Action::None
+            },
+            Event::FetchFailed(fetch, e) =>
+            {
+                self.apply_fetched(fetch, Err(e));
+                Action::None
+            },
+            Event::SearchLoaded(query, result) =>
+            {
+                self.apply_search(&query, result);
+                Action::None
+            }
+        };
+    }
+
+    // hands a key press to the current screen, putting up the loading screen for a fetch it starts
+    fn handle_key(&mut self, key: KeyEvent) -> Action
+    {
+        let action: Action = match self.screen
+        {
+            Screen::FORECAST => ui::forecast_screen::ForecastView.handle_key(self, key),
+            Screen::MENU => ui::menu_screen::MenuView.handle_key(self, key),
+            Screen::HOURLY => ui::hourly_screen::HourlyView.handle_key(self, key),
+            Screen::DAY => ui::day_screen::DayView.handle_key(self, key),
+            Screen::SEARCH => ui::search_screen::SearchView.handle_key(self, key),
+            Screen::ERROR => ui::error_screen::ErrorView.handle_key(self, key),
+            Screen::OPTIONS => ui::options_screen::OptionsView.handle_key(self, key),
+            Screen::LOADING => ui::loading_screen::LoadingView.handle_key(self, key)
+        };
+
+        if action == Action::Fetch
+        {
+            self.loading_from = self.screen;
+            self.screen = Screen::LOADING;
+        }
+
+        return action;
+    }
+
+    // drops a result for settings that have since changed, since the fetch for the new ones replaces it
+    fn apply_fetched(&mut self, fetch: Fetch, result: Result<Forecast, WeatherError>)
+    {
+        // only the refresh that put the spinner up takes it down, whatever it brought back
+        if fetch.kind == FetchKind::Refresh
+        {
+            self.fetching = false;
+        }
+
+        if !fetch.matches(&self.settings)
+        {
+            return;
+        }
+
+        match fetch.kind
+        {
+            FetchKind::Load if self.screen == Screen::LOADING => self.apply_fetch(result),
+            _ => self.apply_refresh(result)
+        }
+    }
+
+    // swaps in a forecast pushed by a refresh, keeping the stale one if it failed
+    pub fn apply_refresh(&mut self, result: Result<Forecast, WeatherError>)
+    {
+        match result
+        {
+            Ok(fetched) =>
+            {
+                self.forecast = fetched;
+                self.day_index = self.day_index.min(self.forecast.weekly.days.len().saturating_sub(1));
+                self.refresh_failed = false;
+
+                // a refresh can leave no days for the detail screen to show
+                if self.screen == Screen::DAY && self.forecast.weekly.days.is_empty()
+                {
+                    self.screen = Screen::FORECAST;
+                }
+
+                if self.screen == Screen::ERROR
+                {
+                    self.fetch_error = None;
+                    self.screen = Screen::FORECAST;
+                }
+            },
+            Err(_) => self.refresh_failed = true
+        }
+    }
+
+    // takes the result of a fetch the user waited on, going to the error screen if it failed
+    pub fn apply_fetch(&mut self, result: Result<Forecast, WeatherError>)
+    {
+        match result
+        {
+            // the days may have shifted, so starting back on today
+            Ok(fetched) =>
+            {
+                self.forecast = fetched;
+                self.day_index = self.forecast.weekly.today_index();
+                self.fetch_error = None;
+                self.refresh_failed = false;
+
+                // a new location or a retry is shown straight away, any other screen is gone back to
+                self.screen = match self.loading_from
+                {
+                    Screen::SEARCH | Screen::ERROR => Screen::FORECAST,
+                    screen => screen
+                };
+            },
+            Err(e) =>
+            {
+                self.fetch_error = Some(e);
+                self.screen = Screen::ERROR;
+            }
+        }
+    }
+
+    // takes the result of a location search, unless the query was changed while it ran
+    pub fn apply_search(&mut self, query: &str, result: Result<Vec<GeocodingResult>, WeatherError>)
+    {
+        if query != self.search_query
+        {
+            return;
+        }
+
+        match result
+        {
+            Ok(results) =>
+            {
+                self.search_status = format!("{} matches for \"{}\"", results.len(), self.search_query.trim());
+                self.search_results = results;
+            },
+            Err(e) =>
+            {
+                self.search_status = format!("search failed: {}", e);
+                self.search_results.clear();
+            }
+        }
+
+        self.search_state.select(if self.search_results.is_empty() { None } else { Some(0) });
+        self.search_dirty = false;
+    }
+
+    // runs on the defaults without ever saving, leaving the unreadable file for the user to fix
+    pub fn settings_unreadable(&mut self, error: SettingsError)
+    {
+        self.options_status = format!("{}, changes won't be saved", error);
+        self.settings_error = Some(error);
+    }
+
+    // hands changed settings to the refresh task and saves the changed field, reporting how that went on the options screen
+    pub fn save_settings(&mut self, field: SettingField)
+    {
+        self.settings_tx.send_replace(self.settings.clone());
+
+        if let Some(error) = &self.settings_error
+        {
+            self.options_status = format!("{} not saved, {}", field.label(), error);
+            return;
+        }
+
+        // the flags given for this run stay out of the file
+        self.saved.copy_field(&self.settings, field);
+
+        let saved_to: io::Result<()> = match &self.config_path
+        {
+            Some(path) => self.saved.save_to(path),
+            None => Err(io::Error::new(io::ErrorKind::NotFound, "no config directory"))
+        };
+
+        self.options_status = match saved_to
+        {
+            Ok(()) => format!("{} saved", field.label()),
+            Err(e) => format!("failed to save settings: {}", e)
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    fn app() -> App
+    {
+        let settings: Settings = Settings::default();
+        let (settings_tx, _) = watch::channel::<Settings>(settings.clone());
+
+        return App::new(settings.clone(), settings, settings_tx, None);
+    }
+
+    fn key(code: KeyCode) -> Event
+    {
+        return Event::Key(KeyEvent::new(code, KeyModifiers::NONE));
+    }
+
+    #[test]
+    fn opens_the_selected_menu_entry()
+    {
+        let mut app: App = app();
+        app.screen = Screen::MENU;
+
+        app.handle_event(key(KeyCode::Char('j')));
+        app.handle_event(key(KeyCode::Char('j')));
+
+        assert_eq!(app.handle_event(key(KeyCode::Enter)), Action::None);
+        assert_eq!(app.screen, Screen::SEARCH);
+    }
+
+    #[test]
+    fn quits_from_screens_but_types_q_into_a_search()
+    {
+        let mut app: App = app();
+        assert_eq!(app.handle_event(key(KeyCode::Char('q'))), Action::Quit);
+
+        app.screen = Screen::SEARCH;
+        assert_eq!(app.handle_event(key(KeyCode::Char('q'))), Action::None);
+        assert_eq!(app.search_query, "q");
+        assert_eq!(app.handle_event(Event::Key(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL))), Action::Quit);
+        assert_eq!(app.handle_event(Event::InputClosed(None)), Action::Quit);
+    }
+
+    #[test]
+    fn leaves_the_loading_screen_for_the_first_forecast_or_its_error()
+    {
+        let mut app: App = app();
+        assert_eq!(app.screen, Screen::LOADING);
+
+        app.handle_event(Event::FetchFailed(Fetch::new(FetchKind::Load, &app.settings), WeatherError::Api(String::from("down"))));
+        assert_eq!(app.screen, Screen::ERROR);
+
+        app.screen = Screen::LOADING;
+        app.handle_event(Event::DataLoaded(Fetch::new(FetchKind::Load, &app.settings), Forecast::default()));
+        assert_eq!(app.screen, Screen::FORECAST);
+        assert!(app.fetch_error.is_none());
+    }
+
+    #[test]
+    fn comes_back_to_the_options_after_a_refetch()
+    {
+        let mut app: App = app();
+        app.screen = Screen::OPTIONS;
+        app.options_state.select(SettingField::ALL.iter().position(|field| *field == SettingField::ForecastDays));
+
+        assert_eq!(app.handle_event(key(KeyCode::Enter)), Action::Fetch);
+        assert_eq!(app.screen, Screen::LOADING);
+
+        app.handle_event(Event::DataLoaded(Fetch::new(FetchKind::Load, &app.settings), Forecast::default()));
+        assert_eq!(app.screen, Screen::OPTIONS);
+    }
+
+    #[test]
+    fn opens_the_search_for_the_location_with_any_key()
+    {
+        for code in [KeyCode::Enter, KeyCode::Char('l'), KeyCode::Right, KeyCode::Char('h'), KeyCode::Left]
+        {
+            let mut app: App = app();
+            app.screen = Screen::OPTIONS;
+            app.options_state.select(SettingField::ALL.iter().position(|field| *field == SettingField::Location));
+
+            assert_eq!(app.handle_event(key(code)), Action::None);
+            assert_eq!(app.screen, Screen::SEARCH);
+            assert!(app.options_status.is_empty());
+        }
+    }
+
+    #[test]
+    fn drops_results_for_a_query_that_was_changed()
+    {
+        let mut app: App = app();
+        app.screen = Screen::SEARCH;
+
+        app.handle_event(key(KeyCode::Char('b')));
+        assert_eq!(app.handle_event(key(KeyCode::Enter)), Action::Search);
+        assert_eq!(app.search_status, "searching for \"b\"...");
+
+        app.handle_event(key(KeyCode::Char('e')));
+        app.handle_event(Event::SearchLoaded(String::from("b"), Ok(Vec::new())));
+        assert!(app.search_dirty);
+
+        app.handle_event(Event::SearchLoaded(String::from("be"), Ok(Vec::new())));
+        assert!(!app.search_dirty);
+        assert_eq!(app.search_status, "0 matches for \"be\"");
+    }
+
+    #[test]
+    fn scrolls_lists_with_the_mouse()
+    {
+        let mut app: App = app();
+        app.screen = Screen::OPTIONS;
+
+        let scroll = |kind: MouseEventKind| Event::Mouse(MouseEvent { kind, column: 0, row: 0, modifiers: KeyModifiers::NONE });
+
+        app.handle_event(scroll(MouseEventKind::ScrollDown));
+        app.handle_event(scroll(MouseEventKind::ScrollDown));
+        app.handle_event(scroll(MouseEventKind::ScrollUp));
+        assert_eq!(app.options_state.selected(), Some(1));
+    }
+
+    #[test]
+    fn leaves_an_unreadable_settings_file_alone()
+    {
+        let path: PathBuf = std::env::temp_dir().join(format!("weatherman-unreadable-{}.json", std::process::id()));
+        std::fs::write(&path, r#"{ "theme": "Plaid" }"#).unwrap();
+
+        let error: SettingsError = Settings::load_from(&path).unwrap_err();
+
+        let mut app: App = app();
+        app.config_path = Some(path.clone());
+        app.settings_unreadable(error);
+        app.save_settings(SettingField::Theme);
+
+        assert!(app.options_status.starts_with("theme not saved"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), r#"{ "theme": "Plaid" }"#);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn saves_only_the_changed_setting_over_the_flags()
+    {
+        let path: PathBuf = std::env::temp_dir().join(format!("weatherman-flags-{}.json", std::process::id()));
+
+        let mut app: App = app();
+        app.config_path = Some(path.clone());
+
+        // as if --city and --days were given
+        app.settings.location = Location::new("Elsewhere", 10.0, 20.0, None, "auto");
+        app.settings.horizon.forecast_days = 3;
+
+        app.settings.cycle(SettingField::Theme, true);
+        app.save_settings(SettingField::Theme);
+
+        let saved: Settings = Settings::load_from(&path).unwrap();
+        assert_eq!(saved.theme, app.settings.theme);
+        assert_eq!(saved.location, Settings::default().location);
+        assert_eq!(saved.horizon, Settings::default().horizon);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn refreshes_once_at_a_time()
+    {
+        let mut app: App = app();
+        app.screen = Screen::FORECAST;
+
+        assert_eq!(app.handle_event(key(KeyCode::Char('r'))), Action::Refresh);
+        assert!(app.fetching);
+        assert_eq!(app.handle_event(key(KeyCode::Char('r'))), Action::None);
+
+        app.handle_event(Event::FetchFailed(Fetch::new(FetchKind::Refresh, &app.settings), WeatherError::Api(String::from("down"))));
+        assert!(!app.fetching);
+        assert!(app.refresh_failed);
+    }
+
+    #[test]
+    fn drops_forecasts_fetched_for_other_settings()
+    {
+        let mut app: App = app();
+        app.screen = Screen::FORECAST;
+        app.handle_event(key(KeyCode::Char('r')));
+
+        let old: Fetch = Fetch::new(FetchKind::Refresh, &app.settings);
+        app.settings.location = Location::new("Elsewhere", 10.0, 20.0, None, "auto");
+
+        // a background fetch finishing doesn't end the manual one
+        app.handle_event(Event::FetchFailed(Fetch::new(FetchKind::Background, &app.settings), WeatherError::Api(String::from("down"))));
+        assert!(app.fetching);
+        assert!(app.refresh_failed);
+
+        // the manual one is done, but what it brought back is for the old place
+        app.handle_event(Event::DataLoaded(old, Forecast::default()));
+        assert!(!app.fetching);
+        assert!(app.refresh_failed);
+    }
+}
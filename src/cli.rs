@@ -0,0 +1,171 @@
+use std::{fmt::{self, Display, Formatter}, path::PathBuf};
+use clap::{Parser, ValueEnum};
+
+use crate::weather::{self, GeocodingResult, Location, UnitSystem, WeatherError};
+use crate::settings::Settings;
+
+// the flags weatherman takes, anything given overrides the saved settings for this run
+#[derive(Parser, Debug)]
+#[command(name = "weatherman", version, about = "Weather forecasts in the terminal, from the Open-Meteo api")]
+pub struct Args
+{
+    /// Latitude of the place to forecast, in degrees north
+    #[arg(long, requires = "lon", allow_negative_numbers = true)]
+    pub lat: Option<f64>,
+
+    /// Longitude of the place to forecast, in degrees east
+    #[arg(long, requires = "lat", allow_negative_numbers = true)]
+    pub lon: Option<f64>,
+
+    /// Place to forecast, looked up by name and taking the best match
+    #[arg(long, conflicts_with_all = ["lat", "lon"])]
+    pub city: Option<String>,
+
+    /// Units to show the forecast in
+    #[arg(long, value_enum)]
+    pub units: Option<UnitChoice>,
+
+    /// How many days to forecast, today included
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=16))]
+    pub days: Option<u8>,
+
+    /// Settings file to read and save to instead of the one in the config directory
+    #[arg(long, value_name = "PATH")]
+    pub config: Option<PathBuf>,
+
+    /// Print the forecast and exit instead of opening the interface
+    #[arg(long)]
+    pub no_tui: bool,
+
+    /// How to print the forecast, implies --no-tui
+    #[arg(long, value_enum)]
+    pub format: Option<OutputFormat>
+}
+
+// the unit presets that can be picked from the command line
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum UnitChoice
+{
+    Metric,
+    Imperial,
+    Mixed
+}
+
+impl From<UnitChoice> for UnitSystem
+{
+    fn from(choice: UnitChoice) -> UnitSystem
+    {
+        return match choice
+        {
+            UnitChoice::Metric => UnitSystem::Metric,
+            UnitChoice::Imperial => UnitSystem::Imperial,
+            UnitChoice::Mixed => UnitSystem::Mixed
+        };
+    }
+}
+
+// how the forecast is printed when the interface isn't opened
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+pub enum OutputFormat
+{
+    Json,
+    Table,
+    Oneline
+}
+
+// everything that can stop the flags from being applied
+#[derive(Debug)]
+pub enum CliError
+{
+    Search(WeatherError),
+    // the city lookup came back empty
+    NoMatch(String)
+}
+
+impl Display for CliError
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        return match self
+        {
+            CliError::Search(e) => fmt.write_fmt(format_args!("failed to look up the city: {}", e)),
+            CliError::NoMatch(city) => fmt.write_fmt(format_args!("no place called \"{}\" was found", city))
+        };
+    }
+}
+
+impl Args
+{
+    // the format to print in, or none when the interface should open
+    pub fn output(&self) -> Option<OutputFormat>
+    {
+        return match (self.format, self.no_tui)
+        {
+            (Some(format), _) => Some(format),
+            (None, true) => Some(OutputFormat::Table),
+            (None, false) => None
+        };
+    }
+
+    // where settings are read from and saved to
+    pub fn config_path(&self) -> Option<PathBuf>
+    {
+        return self.config.clone().or_else(Settings::path);
+    }
+
+    // overrides the settings with the flags that were given, looking the city up if there is one
+    pub async fn apply(&self, settings: &mut Settings) -> Result<(), CliError>
+    {
+        if let (Some(latitude), Some(longitude)) = (self.lat, self.lon)
+        {
+            // the api works out the timezone from the coordinates
+            settings.location = Location::new(&format!("{:.2}, {:.2}", latitude, longitude), latitude, longitude, None, "auto");
+        }
+
+        if let Some(city) = &self.city
+        {
+            let results: Vec<GeocodingResult> = weather::search_locations(city).await.map_err(CliError::Search)?;
+
+            settings.location = results.first()
+                .map(Location::from)
+                .ok_or(CliError::NoMatch(city.clone()))?;
+        }
+
+        if let Some(units) = self.units.and_then(|choice| UnitSystem::from(choice).units())
+        {
+            settings.units = units;
+        }
+
+        if let Some(days) = self.days
+        {
+            settings.horizon.forecast_days = days;
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn prints_instead_of_opening_the_interface_when_asked()
+    {
+        assert_eq!(Args::try_parse_from(["weatherman"]).unwrap().output(), None);
+        assert_eq!(Args::try_parse_from(["weatherman", "--no-tui"]).unwrap().output(), Some(OutputFormat::Table));
+        assert_eq!(Args::try_parse_from(["weatherman", "--format", "json"]).unwrap().output(), Some(OutputFormat::Json));
+    }
+
+    #[test]
+    fn rejects_half_a_location_and_impossible_days()
+    {
+        assert!(Args::try_parse_from(["weatherman", "--lat", "52.5"]).is_err());
+        assert!(Args::try_parse_from(["weatherman", "--lat", "52.5", "--lon", "13.4", "--city", "Berlin"]).is_err());
+        assert!(Args::try_parse_from(["weatherman", "--days", "17"]).is_err());
+
+        let args: Args = Args::try_parse_from(["weatherman", "--lat", "-33.9", "--lon", "151.2", "--days", "10"]).unwrap();
+        assert_eq!((args.lat, args.lon, args.days), (Some(-33.9), Some(151.2), Some(10)));
+    }
+}
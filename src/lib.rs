@@ -0,0 +1,13 @@
+// explicit returns and single-arm input matches are the house style
+#![allow(clippy::needless_return, clippy::single_match)]
+
+// the open-meteo client and forecast models, usable without the terminal ui
+pub mod weather;
+
+// everything the request functions take and return, so callers don't have to reach into the module
+pub use weather::
+{
+    fetch_forecast, generate_request, search_locations,
+    APIResponse, Forecast, WeeklyForecast, DailyWeather, HourlyForecast, HourlyWeather, CurrentWeather, GeocodingResult,
+    Location, Units, UnitSystem, TemperatureUnit, WindUnit, PrecipitationUnit, Horizon, WeatherCondition, WeatherError, ForecastError
+};
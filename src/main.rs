@@ -1,245 +1,253 @@
+// explicit returns and single-arm input matches are the house style
+#![allow(clippy::needless_return, clippy::single_match)]
+
+pub mod app;
+pub mod cli;
+pub mod report;
+pub mod settings;
+pub mod terminal;
 pub mod ui;
-pub mod weather;
 
-use tui::
+// the forecast client lives in the library so it can be used without the ui
+use weatherman::weather;
+
+use std::{time::Duration, path::PathBuf, process};
+use clap::Parser;
+use tokio::sync::{mpsc, watch};
+use crossterm::event::EventStream;
+use futures::StreamExt;
+use app::{App, Action, Event, Fetch, FetchKind};
+use settings::{Settings, SettingsError};
+use cli::{Args, OutputFormat};
+use terminal::TerminalGuard;
+
+// re-fetches the forecast every refresh interval in the background, picking up setting changes as they are made
+fn spawn_refresh_task(mut settings_rx: watch::Receiver<Settings>, tx: mpsc::UnboundedSender<Event>)
 {
-    backend::CrosstermBackend,
-    widgets::{Paragraph, ListState, List},
-    layout::Rect,
-    Terminal
-};
-use std::
+    tokio::spawn
+    (
+        async move
+        {
+            loop
+            {
+                let minutes: u64 = settings_rx.borrow().refresh_interval;
+
+                // refreshing is turned off, so waiting for the settings to change
+                if minutes == 0
+                {
+                    if settings_rx.changed().await.is_err()
+                    {
+                        return;
+                    }
+
+                    continue;
+                }
+
+                tokio::select!
+                {
+                    _ = tokio::time::sleep(Duration::from_secs(minutes * 60)) =>
+                    {
+                        let fetch: Fetch = Fetch::new(FetchKind::Background, &settings_rx.borrow());
+
+                        // the main loop is gone
+                        if tx.send(fetch_forecast(fetch).await).is_err()
+                        {
+                            return;
+                        }
+                    },
+                    changed = settings_rx.changed() =>
+                    {
+                        if changed.is_err()
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+        }
+    );
+}
+
+// fetches the forecast a fetch asks for, handing back the result tagged with it
+async fn fetch_forecast(fetch: Fetch) -> Event
 {
-    time::{Instant, Duration},
-    sync::mpsc, thread
-};
-use ui::{forecast_screen::ForecastPosition, Screen};
-use crossterm::event::KeyCode;
-use weather::WeeklyForecast;
-use std::io;
+    let result = weather::fetch_forecast(&fetch.location, &fetch.units, &fetch.horizon).await;
 
-#[tokio::main]
-async fn main()
+    return Event::fetched(fetch, result);
+}
+
+// fetches the forecast for the current settings alongside the ui, sending back the result
+fn spawn_fetch(kind: FetchKind, settings: &Settings, tx: mpsc::UnboundedSender<Event>)
 {
-    // initializing terminal
-    let mut terminal: Terminal<CrosstermBackend<io::Stdout>> = Terminal::new(CrosstermBackend::new(io::stdout())).expect("failed to instantiate crossterm terminal");
-    terminal.clear().expect("failed to clear the terminal");
+    let fetch: Fetch = Fetch::new(kind, settings);
 
-    // drawing loading screen while waiting for forecast api to respond
-    terminal.draw
+    tokio::spawn
     (
-        move |rect|
+        async move
         {
-            let loading_panel: Rect = ui::loading_screen::get_loading_panel(rect.size());
+            // the main loop may already be gone
+            let _ = tx.send(fetch_forecast(fetch).await);
+        }
+    );
+}
 
-            let loading_message: Paragraph = ui::loading_screen::get_loading_message();
+// looks up a place name alongside the ui, sending back the results with the query they are for
+fn spawn_search(query: &str, tx: mpsc::UnboundedSender<Event>)
+{
+    let query: String = query.to_string();
 
-            rect.render_widget(loading_message, loading_panel);
-        }
-    ).expect("failed to draw on terminal");
+    tokio::spawn
+    (
+        async move
+        {
+            let result = weather::search_locations(&query).await;
 
-    // getting api response
-    let weekly_forecast: WeeklyForecast = WeeklyForecast::from(weather::generate_request().await.expect("failed to get api response"));
+            // the main loop may already be gone
+            let _ = tx.send(Event::SearchLoaded(query, result));
+        }
+    );
+}
 
-    // clearing terminal once api has responded
-    terminal.clear().expect("failed to clear the terminal");
+// forwards terminal input and a steady tick, which keeps the spinner and the "last updated" time moving while no keys are pressed
+fn spawn_input_task(tx: mpsc::UnboundedSender<Event>)
+{
+    const TICK_RATE: Duration = Duration::from_millis(200);
 
-    // message passing for input
-    let (tx, rx) = mpsc::channel::<crossterm::event::Event>();
-    thread::spawn
+    tokio::spawn
     (
-        move ||
+        async move
         {
-            const TICK_RATE: Duration = Duration::from_millis(200);
-            let mut last_poll: Instant = Instant::now();
+            let mut input: EventStream = EventStream::new();
+            let mut ticks: tokio::time::Interval = tokio::time::interval(TICK_RATE);
 
             loop
             {
-                if last_poll.elapsed() >= TICK_RATE
+                let event: Event = tokio::select!
                 {
-                    last_poll = Instant::now();
-                }
-
-                if crossterm::event::poll(TICK_RATE).expect("how is this even possible")
-                {
-                    if let crossterm::event::Event::Key(key) = crossterm::event::read().expect("error encountered in reading from crossterm")
+                    _ = ticks.tick() => Event::Tick,
+                    read = input.next() => match read
                     {
-                        tx.send(crossterm::event::Event::Key(key)).expect("failed to send key event through mpsc");
+                        Some(Ok(crossterm::event::Event::Key(key))) => Event::Key(key),
+                        Some(Ok(crossterm::event::Event::Mouse(mouse))) => Event::Mouse(mouse),
+                        Some(Ok(crossterm::event::Event::Resize(width, height))) => Event::Resize(width, height),
+                        Some(Ok(_)) => continue,
+                        // the terminal can't be read from anymore, which the main loop quits on
+                        Some(Err(e)) =>
+                        {
+                            let _ = tx.send(Event::InputClosed(Some(e)));
+                            return;
+                        },
+                        None =>
+                        {
+                            let _ = tx.send(Event::InputClosed(None));
+                            return;
+                        }
                     }
+                };
+
+                // the main loop is gone
+                if tx.send(event).is_err()
+                {
+                    return;
                 }
             }
         }
     );
+}
 
-    // setting up main loop
-    let mut screen: Screen = Screen::FORECAST;
-    
-    // initializing menu state
-    let mut menu_state: ListState = ListState::default();
-    menu_state.select(Some(0));
-
-    loop
+// fetches the forecast and prints it in the given format, exiting with an error if the fetch fails
+async fn print_forecast(format: OutputFormat, settings: &Settings)
+{
+    match weather::fetch_forecast(&settings.location, &settings.units, &settings.horizon).await
     {
-        // checking for screen
-        match screen
+        Ok(forecast) => println!("{}", report::render(format, &forecast, settings)),
+        Err(e) =>
         {
-            Screen::FORECAST =>
-            {
-                // drawing home screen
-                terminal.draw
-                (
-                    |rect|
-                    {
-                        // drawing simple elements
-                        let main_panel: Vec<Rect> = ui::forecast_screen::get_forecast_panel(rect.size());
+            eprintln!("weatherman: {}", e);
+            process::exit(1);
+        }
+    }
+}
 
-                        let title: Paragraph = ui::forecast_screen::get_forecast_title();
-                        let controls: Paragraph = ui::forecast_screen::get_forecast_controls();
+#[tokio::main]
+async fn main()
+{
+    let args: Args = Args::parse();
 
-                        rect.render_widget(title, main_panel[0]);
-                        rect.render_widget(controls, main_panel[2]);
+    // reading saved settings, then whatever the flags override for this run
+    let config_path: Option<PathBuf> = args.config_path();
+    let (saved, settings_error): (Settings, Option<SettingsError>) = match config_path.as_deref().map(Settings::load_from)
+    {
+        Some(Ok(settings)) => (settings, None),
+        Some(Err(e)) => (Settings::default(), Some(e)),
+        None => (Settings::default(), None)
+    };
 
-                        // drawing forecast
-                        let forecast_panel: Vec<Rect> = ui::forecast_screen::get_forecast_slot_panel(main_panel[1]);
-                        let forecast_slots: Vec< Vec<Rect> > = weekly_forecast.days.iter()
-                            .enumerate()
-                            .map(|(i, _)| ui::forecast_screen::get_forecast_slot_layout(forecast_panel[i]))
-                            .collect();
+    let mut settings: Settings = saved.clone();
 
-                        let daily_paragraph_sets: Vec< Vec<Paragraph> > = weekly_forecast.days.iter().enumerate()
-                            .map(|(index, day)| ui::forecast_screen::get_forecast_paragraphs(day, ForecastPosition::from_index(&index)))
-                            .collect();
+    if let Err(e) = args.apply(&mut settings).await
+    {
+        eprintln!("weatherman: {}", e);
+        process::exit(1);
+    }
 
-                        for paragraph_set in daily_paragraph_sets.into_iter().zip(forecast_slots.into_iter())
-                        {
-                            for i in 0..paragraph_set.0.len()
-                            {
-                                rect.render_widget(paragraph_set.0[i].clone(), paragraph_set.1[i]);
-                            }
-                        }
-                    }
-                ).expect("error encountered in drawing on the terminal");
+    // printing the forecast for scripts without touching the terminal
+    if let Some(format) = args.output()
+    {
+        if let Some(e) = &settings_error
+        {
+            eprintln!("weatherman: {}, using the defaults", e);
+        }
 
-                // handling input for home screen
-                match rx.recv().expect("faild to read from mpsc")
-                {
-                    crossterm::event::Event::Key(key) => match key.code
-                    {
-                        KeyCode::Char('q') => break,
-                        KeyCode::Char('m') => screen = Screen::MENU,
-                        _ => {}
-                    },
-                    _ => {}
-                }
-            },
-            Screen::MENU =>
-            {
-                terminal.draw
-                (
-                    |rect|
-                    {
-                        let menu_panel: Vec<Rect> = ui::menu_screen::get_menu_panel(rect.size());
+        print_forecast(format, &settings).await;
+        return;
+    }
 
-                        let menu_list: List = ui::menu_screen::get_menu_list();
+    // initializing terminal, which is restored when this goes out of scope or something panics
+    let mut terminal: TerminalGuard = TerminalGuard::new().expect("failed to set up the terminal");
 
-                        rect.render_stateful_widget(menu_list, menu_panel[1], &mut menu_state);
-                    }
-                ).expect("failed to draw on terminal");
+    // the refresh task follows the settings as they change
+    let (settings_tx, settings_rx) = watch::channel::<Settings>(settings.clone());
 
-                match rx.recv().expect("failed to read from mpsc")
-                {
-                    crossterm::event::Event::Key(key) => match key.code
-                    {
-                        KeyCode::Char('q') => break,
-                        KeyCode::Char('j') =>
-                        {
-                            if let Some(sel_index) = menu_state.selected()
-                            {
-                                menu_state.select(Some(((sel_index) + 1) % 3));
-                            }
-                            else
-                            {
-                                menu_state.select(Some(0));
-                            }
-                        },
-                        KeyCode::Char('k') =>
-                        {
-                            if let Some(sel_index) = menu_state.selected()
-                            {
-                                if sel_index == 0
-                                {
-                                    menu_state.select(Some(2));
-                                }
-                                else
-                                {
-                                    menu_state.select(Some(sel_index - 1));
-                                }
-                            }
-                            else
-                            {
-                                menu_state.select(Some(2));
-                            }
-                        },
-                        KeyCode::Enter =>
-                        {
-                            if let Some(sel_index) = menu_state.selected()
-                            {
-                                screen = match sel_index
-                                {
-                                    0 => Screen::FORECAST,
-                                    1 => Screen::HOURLY,
-                                    2 => Screen::OPTIONS,
-                                    _ => Screen::FORECAST
-                                };
-                            }
-                        },
-                        _ => {}
-                    },
-                    _ => {}
-                }
-            },
-            Screen::HOURLY =>
-            {
-                terminal.draw
-                (
-                    |rect|
-                    {
-                        
-                    }
-                ).expect("failed to draw on terminal");
+    let mut app: App = App::new(settings, saved, settings_tx, config_path);
 
-                match rx.recv().expect("failed to read from mpsc")
-                {
-                    crossterm::event::Event::Key(key) => match key.code
-                    {
-                        KeyCode::Char('q') => break,
-                        _ => {}
-                    },
-                    _ => {}
-                }
-            },
-            Screen::OPTIONS =>
-            {
-                terminal.draw
-                (
-                    |rect|
-                    {
+    if let Some(e) = settings_error
+    {
+        app.settings_unreadable(e);
+    }
 
-                    }
-                ).expect("failed to draw on terminal");
+    // input, ticks and fetched forecasts all arrive on the one channel, with input listened to first so the app can be quit while it loads
+    let (tx, mut rx) = mpsc::unbounded_channel::<Event>();
+    spawn_input_task(tx.clone());
 
-                match rx.recv().expect("failed to read from mpsc")
-                {
-                    crossterm::event::Event::Key(key) => match key.code
-                    {
-                        KeyCode::Char('q') => break,
-                        _ => {}
-                    },
-                    _ => {}
-                }
-            }
+    // getting api response behind the loading screen, which goes to the error screen if the first request fails
+    spawn_fetch(FetchKind::Load, &app.settings, tx.clone());
+
+    // refreshing in the background with whatever the settings currently are
+    spawn_refresh_task(settings_rx, tx.clone());
+
+    loop
+    {
+        terminal.draw(|frame| app.render(frame)).expect("failed to draw on terminal");
+
+        // every event redraws, the screens update the app and anything that has to wait on the network is started here
+        let event: Event = rx.recv().await.expect("the main loop holds a sender, so the channel stays open");
+
+        match app.handle_event(event)
+        {
+            Action::None => {},
+            Action::Quit => break,
+            Action::Refresh => spawn_fetch(FetchKind::Refresh, &app.settings, tx.clone()),
+            Action::Fetch => spawn_fetch(FetchKind::Load, &app.settings, tx.clone()),
+            Action::Search => spawn_search(&app.search_query, tx.clone())
         }
     }
 
-    terminal.clear().expect("failed to clear terminal");
+    // restoring the terminal first so the error isn't drawn over
+    if let Some(e) = app.input_error
+    {
+        drop(terminal);
+        eprintln!("weatherman: couldn't read from the terminal: {}", e);
+        process::exit(1);
+    }
 }
@@ -0,0 +1,306 @@
+use serde_json::{json, Value};
+
+use crate::weather::{CurrentWeather, DailyWeather, Forecast, HourlyForecast, WeeklyForecast, display_option, display_measurement};
+use crate::settings::Settings;
+use crate::cli::OutputFormat;
+use crate::ui::forecast_screen::display_field;
+
+// the forecast as text for printing instead of drawing, in the given format
+pub fn render(format: OutputFormat, forecast: &Forecast, settings: &Settings) -> String
+{
+    return match format
+    {
+        OutputFormat::Json => get_json(forecast, settings),
+        OutputFormat::Table => get_table(forecast, settings),
+        OutputFormat::Oneline => get_oneline(forecast, settings)
+    };
+}
+
+// every day in full with the raw numbers, for scripts to pick apart
+fn get_json(forecast: &Forecast, settings: &Settings) -> String
+{
+    let report: Value = json!
+    ({
+        "location": settings.location,
+        "current": forecast.current.as_ref().map(get_current_json),
+        "current_units": get_current_units(&forecast.hourly),
+        "daily": forecast.weekly.days.iter().map(get_day_json).collect::< Vec<Value> >(),
+        "daily_units": get_daily_units(&forecast.weekly)
+    });
+
+    return serde_json::to_string_pretty(&report).unwrap_or_default();
+}
+
+fn get_current_json(current: &CurrentWeather) -> Value
+{
+    return json!
+    ({
+        "time": current.time.to_rfc3339(),
+        "temperature": get_number(Some(current.temp)),
+        "windspeed": get_number(Some(current.windspeed)),
+        "winddirection": get_number(Some(current.winddirection)),
+        "condition": current.condition.map(|n| n.to_string()),
+        "is_day": current.is_day
+    });
+}
+
+// the current conditions come in the same units as their hourly counterparts
+fn get_current_units(hourly: &HourlyForecast) -> Value
+{
+    return json!
+    ({
+        "temperature": hourly.units.get("temperature_2m"),
+        "windspeed": hourly.units.get("windspeed_10m"),
+        "winddirection": hourly.units.get("winddirection_10m")
+    });
+}
+
+fn get_day_json(day: &DailyWeather) -> Value
+{
+    return json!
+    ({
+        "date": day.date.to_string(),
+        "condition": day.condition.map(|n| n.to_string()),
+        "temperature_max": get_number(day.max_temp),
+        "temperature_min": get_number(day.min_temp),
+        "apparent_temperature_max": get_number(day.apparent_max_temp),
+        "apparent_temperature_min": get_number(day.apparent_min_temp),
+        "sunrise": day.sunrise.map(|n| n.to_rfc3339()),
+        "sunset": day.sunset.map(|n| n.to_rfc3339()),
+        "sunshine_seconds": day.sunshine.map(|n| n.num_seconds()),
+        "uv_index": get_number(day.uv_index),
+        "precipitation": get_number(day.precipitation),
+        "precipitation_hours": get_number(day.precipitation_hours),
+        "precipitation_probability": get_number(day.precipitation_probability),
+        "rain": get_number(day.rain),
+        "showers": get_number(day.showers),
+        "snowfall": get_number(day.snowfall),
+        "windspeed_max": get_number(day.max_windspeed),
+        "windgusts_max": get_number(day.max_windgusts),
+        "winddirection_dominant": get_number(day.wind_direction),
+        "radiation": get_number(day.radiation)
+    });
+}
+
+// each day's measurements under the names they are given in the report, next to the api variable their unit is reported for
+const DAILY_VARIABLES: [(&str, &str); 16] =
+[
+    ("temperature_max", "temperature_2m_max"),
+    ("temperature_min", "temperature_2m_min"),
+    ("apparent_temperature_max", "apparent_temperature_max"),
+    ("apparent_temperature_min", "apparent_temperature_min"),
+    ("uv_index", "uv_index_max"),
+    ("precipitation", "precipitation_sum"),
+    ("precipitation_hours", "precipitation_hours"),
+    ("precipitation_probability", "precipitation_probability_max"),
+    ("rain", "rain_sum"),
+    ("showers", "showers_sum"),
+    ("snowfall", "snowfall_sum"),
+    ("windspeed_max", "windspeed_10m_max"),
+    ("windgusts_max", "windgusts_10m_max"),
+    ("winddirection_dominant", "winddirection_10m_dominant"),
+    ("radiation", "shortwave_radiation_sum"),
+    ("sunshine_seconds", "sunshine_duration")
+];
+
+// the daily units keyed like the values in each day
+fn get_daily_units(weekly: &WeeklyForecast) -> Value
+{
+    return Value::Object
+    (
+        DAILY_VARIABLES.iter()
+            .map(|(key, variable)| (key.to_string(), json!(weekly.units.get(*variable))))
+            .collect()
+    );
+}
+
+// the api's numbers as they were sent, since widening an f32 would print 0.2 as 0.20000000298023224
+fn get_number(value: Option<f32>) -> Value
+{
+    return json!(value.map(|n| n.to_string().parse::<f64>().unwrap_or(n as f64)));
+}
+
+// a row per day with the fields picked in the settings, padded into columns
+fn get_table(forecast: &Forecast, settings: &Settings) -> String
+{
+    let mut rows: Vec< Vec<String> > = Vec::with_capacity(forecast.weekly.days.len() + 1);
+
+    rows.push
+    (
+        ["date", "condition"].iter()
+            .map(|n| n.to_string())
+            .chain(settings.fields.iter().map(|field| field.short_label().to_string()))
+            .collect()
+    );
+
+    for day in forecast.weekly.days.iter()
+    {
+        rows.push
+        (
+            [settings.date_format.format(day.date), display_option(&day.condition)].into_iter()
+                .chain(settings.fields.iter().map(|field| display_field(field, day, &forecast.weekly, settings)))
+                .collect()
+        );
+    }
+
+    // wide enough for the longest value in each column
+    let widths: Vec<usize> = (0..rows[0].len())
+        .map(|column| rows.iter().map(|row| row[column].chars().count()).max().unwrap_or(0))
+        .collect();
+
+    let lines: Vec<String> = rows.iter()
+        .map
+        (
+            |row| row.iter()
+                .zip(widths.iter())
+                .map(|(value, width)| format!("{:<1$}", value, width))
+                .collect::< Vec<String> >()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        )
+        .collect();
+
+    return format!("{}\n{}", settings.location, lines.join("\n"));
+}
+
+// the conditions right now and today's range, short enough for a status bar or prompt
+fn get_oneline(forecast: &Forecast, settings: &Settings) -> String
+{
+    let today: Option<&DailyWeather> = forecast.weekly.days.get(forecast.weekly.today_index());
+    let unit: String = forecast.weekly.unit("temperature_2m_max");
+
+    let now: String = match &forecast.current
+    {
+        Some(current) => format!("{}{} {}", current.temp, forecast.hourly.unit("temperature_2m"), display_option(&current.condition)),
+        None => display_option(&today.and_then(|day| day.condition))
+    };
+
+    return match today
+    {
+        Some(day) => format!("{}: {}, high {} low {}", settings.location.name, now, display_measurement(&day.max_temp, &unit), display_measurement(&day.min_temp, &unit)),
+        None => format!("{}: {}", settings.location.name, now)
+    };
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use std::collections::HashMap;
+    use chrono::{DateTime, NaiveDate};
+    use crate::weather::{Location, WeatherCondition, WeeklyForecast};
+    use crate::settings::{DailyField, DateFormat};
+
+    fn units(pairs: &[(&str, &str)]) -> HashMap<String, String>
+    {
+        return pairs.iter().map(|(variable, unit)| (variable.to_string(), unit.to_string())).collect();
+    }
+
+    fn forecast() -> Forecast
+    {
+        let day: DailyWeather = DailyWeather
+        {
+            date: NaiveDate::from_ymd_opt(2024, 5, 1).unwrap(),
+            max_temp: Some(18.4),
+            min_temp: Some(9.1),
+            sunrise: None,
+            sunset: None,
+            precipitation: Some(0.2),
+            max_windspeed: Some(14.0),
+            condition: WeatherCondition::from_code(3),
+            apparent_max_temp: None,
+            apparent_min_temp: None,
+            uv_index: None,
+            precipitation_hours: Some(1.0),
+            max_windgusts: None,
+            wind_direction: None,
+            sunshine: None,
+            precipitation_probability: None,
+            rain: None,
+            showers: None,
+            snowfall: None,
+            radiation: None
+        };
+
+        let current: CurrentWeather = CurrentWeather
+        {
+            time: DateTime::parse_from_rfc3339("2024-05-01T12:00:00+02:00").unwrap(),
+            temp: 15.3,
+            windspeed: 11.2,
+            winddirection: 270.0,
+            condition: WeatherCondition::from_code(3),
+            is_day: true
+        };
+
+        return Forecast
+        {
+            current: Some(current),
+            hourly: HourlyForecast
+            {
+                hours: Vec::new(),
+                units: units(&[("temperature_2m", "°C"), ("windspeed_10m", "km/h"), ("winddirection_10m", "°")])
+            },
+            weekly: WeeklyForecast
+            {
+                days: vec![day],
+                units: units(&[("temperature_2m_max", "°C"), ("temperature_2m_min", "°C"), ("precipitation_sum", "mm"), ("windspeed_10m_max", "km/h")]),
+                utc_offset: None,
+                fetched_at: None
+            }
+        };
+    }
+
+    fn settings() -> Settings
+    {
+        return Settings
+        {
+            location: Location::new("Berlin", 52.52, 13.41, None, "auto"),
+            date_format: DateFormat::YearMonthDay,
+            fields: vec![DailyField::High, DailyField::Low, DailyField::Precipitation],
+            ..Settings::default()
+        };
+    }
+
+    #[test]
+    fn prints_json_with_units_keyed_like_the_values()
+    {
+        let report: Value = serde_json::from_str(&render(OutputFormat::Json, &forecast(), &settings())).unwrap();
+
+        assert_eq!(report["current"]["temperature"], json!(15.3));
+        assert_eq!(report["current_units"], json!({ "temperature": "°C", "windspeed": "km/h", "winddirection": "°" }));
+        assert_eq!(report["daily"][0]["date"], json!("2024-05-01"));
+        assert_eq!(report["daily"][0]["precipitation"], json!(0.2));
+        assert_eq!(report["daily"][0]["sunrise"], Value::Null);
+        assert_eq!(report["daily_units"]["precipitation"], json!("mm"));
+        assert_eq!(report["daily_units"]["temperature_max"], json!("°C"));
+        assert_eq!(report["daily_units"]["uv_index"], Value::Null);
+
+        for key in report["daily_units"].as_object().unwrap().keys()
+        {
+            assert!(report["daily"][0].get(key).is_some(), "no daily value for the {} unit", key);
+        }
+    }
+
+    #[test]
+    fn prints_a_table_of_the_chosen_fields()
+    {
+        let table: String = render(OutputFormat::Table, &forecast(), &settings());
+
+        assert_eq!
+        (
+            table,
+            "Berlin (52.52, 13.41)\n\
+             date        condition  high    low    precip\n\
+             2024-05-01  cloudy     18.4°C  9.1°C  0.2mm"
+        );
+    }
+
+    #[test]
+    fn prints_the_current_conditions_on_one_line()
+    {
+        let line: String = render(OutputFormat::Oneline, &forecast(), &settings());
+
+        assert_eq!(line, "Berlin: 15.3°C cloudy, high 18.4°C low 9.1°C");
+    }
+}
@@ -0,0 +1,657 @@
+use serde::{Serialize, Deserialize};
+use std::{fs, io, fmt, path::{Path, PathBuf}};
+use tui::style::{Style, Color};
+use chrono::{NaiveDate, NaiveTime};
+
+use crate::weather::{WeatherCondition, Location, Horizon, Units, UnitSystem, TemperatureUnit, WindUnit, PrecipitationUnit, beaufort_force, beaufort_description, display_direction, display_measurement};
+
+// how clock times are shown
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TimeFormat
+{
+    TwelveHour,
+    TwentyFourHour
+}
+
+impl TimeFormat
+{
+    pub const ALL: [TimeFormat; 2] = [TimeFormat::TwelveHour, TimeFormat::TwentyFourHour];
+
+    pub fn format(&self, time: NaiveTime) -> String
+    {
+        return match self
+        {
+            TimeFormat::TwelveHour => time.format("%-I:%M%P").to_string(),
+            TimeFormat::TwentyFourHour => time.format("%H:%M").to_string()
+        };
+    }
+}
+
+// how calendar dates are shown
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DateFormat
+{
+    MonthDayYear,
+    DayMonthYear,
+    YearMonthDay,
+    WeekdayMonthDay,
+    WeekdayDayMonth
+}
+
+impl DateFormat
+{
+    pub const ALL: [DateFormat; 5] = [DateFormat::MonthDayYear, DateFormat::DayMonthYear, DateFormat::YearMonthDay, DateFormat::WeekdayMonthDay, DateFormat::WeekdayDayMonth];
+
+    pub fn format(&self, date: NaiveDate) -> String
+    {
+        let pattern: &str = match self
+        {
+            DateFormat::MonthDayYear => "%m/%d/%Y",
+            DateFormat::DayMonthYear => "%d.%m.%Y",
+            DateFormat::YearMonthDay => "%Y-%m-%d",
+            DateFormat::WeekdayMonthDay => "%a %m/%d",
+            DateFormat::WeekdayDayMonth => "%a %d.%m"
+        };
+
+        return date.format(pattern).to_string();
+    }
+}
+
+// colors the whole screen is drawn on top of
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Theme
+{
+    Terminal,
+    Dark,
+    Light
+}
+
+impl Theme
+{
+    pub const ALL: [Theme; 3] = [Theme::Terminal, Theme::Dark, Theme::Light];
+
+    // base style every screen's background is filled with
+    pub fn base_style(&self) -> Style
+    {
+        return match self
+        {
+            Theme::Terminal => Style::default(),
+            Theme::Dark => Style::default().bg(Color::Black).fg(Color::White),
+            Theme::Light => Style::default().bg(Color::White).fg(Color::Black)
+        };
+    }
+}
+
+// how weather conditions are drawn
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum IconStyle
+{
+    Emoji,
+    Ascii
+}
+
+impl IconStyle
+{
+    pub const ALL: [IconStyle; 2] = [IconStyle::Emoji, IconStyle::Ascii];
+
+    pub fn icon(&self, condition: &WeatherCondition) -> &'static str
+    {
+        return match self
+        {
+            IconStyle::Emoji => condition.emoji(),
+            IconStyle::Ascii => condition.ascii()
+        };
+    }
+
+    // the icon followed by the condition's name, or a placeholder for an unknown condition
+    pub fn describe(&self, condition: &Option<WeatherCondition>) -> String
+    {
+        return match condition
+        {
+            Some(condition) => format!("{} {}", self.icon(condition), condition),
+            None => String::from("--")
+        };
+    }
+}
+
+// how windspeeds are shown
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WindDisplay
+{
+    Speed,
+    Beaufort
+}
+
+impl WindDisplay
+{
+    pub const ALL: [WindDisplay; 2] = [WindDisplay::Speed, WindDisplay::Beaufort];
+
+    // the speed, or its beaufort force when the unit is known, followed by the direction when there is one
+    pub fn format(&self, speed: &Option<f32>, unit: &str, direction: &Option<f32>) -> String
+    {
+        let strength: String = match (self, speed, WindUnit::from_suffix(unit))
+        {
+            (WindDisplay::Beaufort, Some(speed), Some(unit)) =>
+            {
+                let force: u8 = beaufort_force(unit.to_meters_per_second(*speed));
+
+                format!("F{} {}", force, beaufort_description(force))
+            },
+            _ => display_measurement(speed, unit)
+        };
+
+        return match direction
+        {
+            Some(_) => format!("{} {}", strength, display_direction(direction)),
+            None => strength
+        };
+    }
+}
+
+// a daily variable that can be listed under each day on the forecast screen
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DailyField
+{
+    High,
+    Low,
+    FeelsLikeHigh,
+    FeelsLikeLow,
+    Sunrise,
+    Sunset,
+    Daylight,
+    Sunshine,
+    UvIndex,
+    Radiation,
+    Precipitation,
+    PrecipitationProbability,
+    PrecipitationHours,
+    Rain,
+    Showers,
+    Snowfall,
+    Wind,
+    Gusts,
+    WindDirection
+}
+
+impl DailyField
+{
+    pub const ALL: [DailyField; 19] =
+    [
+        DailyField::High,
+        DailyField::Low,
+        DailyField::FeelsLikeHigh,
+        DailyField::FeelsLikeLow,
+        DailyField::Sunrise,
+        DailyField::Sunset,
+        DailyField::Daylight,
+        DailyField::Sunshine,
+        DailyField::UvIndex,
+        DailyField::Radiation,
+        DailyField::Precipitation,
+        DailyField::PrecipitationProbability,
+        DailyField::PrecipitationHours,
+        DailyField::Rain,
+        DailyField::Showers,
+        DailyField::Snowfall,
+        DailyField::Wind,
+        DailyField::Gusts,
+        DailyField::WindDirection
+    ];
+
+    // the name used on the day detail screen
+    pub fn label(&self) -> &'static str
+    {
+        return match self
+        {
+            DailyField::High => "high",
+            DailyField::Low => "low",
+            DailyField::FeelsLikeHigh => "feels like high",
+            DailyField::FeelsLikeLow => "feels like low",
+            DailyField::Sunrise => "sunrise",
+            DailyField::Sunset => "sunset",
+            DailyField::Daylight => "daylight",
+            DailyField::Sunshine => "sunshine",
+            DailyField::UvIndex => "uv index",
+            DailyField::Radiation => "radiation",
+            DailyField::Precipitation => "precipitation",
+            DailyField::PrecipitationProbability => "precipitation chance",
+            DailyField::PrecipitationHours => "precipitation hours",
+            DailyField::Rain => "rain",
+            DailyField::Showers => "showers",
+            DailyField::Snowfall => "snowfall",
+            DailyField::Wind => "max winds",
+            DailyField::Gusts => "max gusts",
+            DailyField::WindDirection => "wind from"
+        };
+    }
+
+    // the shorter name used in the narrow forecast columns
+    pub fn short_label(&self) -> &'static str
+    {
+        return match self
+        {
+            DailyField::FeelsLikeHigh => "feels hi",
+            DailyField::FeelsLikeLow => "feels lo",
+            DailyField::Daylight => "light",
+            DailyField::Sunshine => "sun",
+            DailyField::UvIndex => "uv",
+            DailyField::Radiation => "rad",
+            DailyField::Precipitation => "precip",
+            DailyField::PrecipitationProbability => "chance",
+            DailyField::PrecipitationHours => "precip hrs",
+            DailyField::Snowfall => "snow",
+            DailyField::Wind => "winds",
+            DailyField::Gusts => "gusts",
+            DailyField::WindDirection => "from",
+            field => field.label()
+        };
+    }
+
+    // the api variable the field's unit is reported under, if it has one
+    pub fn variable(&self) -> Option<&'static str>
+    {
+        return match self
+        {
+            DailyField::High => Some("temperature_2m_max"),
+            DailyField::Low => Some("temperature_2m_min"),
+            DailyField::FeelsLikeHigh => Some("apparent_temperature_max"),
+            DailyField::FeelsLikeLow => Some("apparent_temperature_min"),
+            DailyField::Radiation => Some("shortwave_radiation_sum"),
+            DailyField::Precipitation => Some("precipitation_sum"),
+            DailyField::PrecipitationProbability => Some("precipitation_probability_max"),
+            DailyField::PrecipitationHours => Some("precipitation_hours"),
+            DailyField::Rain => Some("rain_sum"),
+            DailyField::Showers => Some("showers_sum"),
+            DailyField::Snowfall => Some("snowfall_sum"),
+            DailyField::Wind => Some("windspeed_10m_max"),
+            DailyField::Gusts => Some("windgusts_10m_max"),
+            DailyField::WindDirection => Some("winddirection_10m_dominant"),
+            _ => None
+        };
+    }
+}
+
+// named lists of fields that can be switched between in one go
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum FieldSet
+{
+    Standard,
+    Temperature,
+    Precipitation,
+    Wind,
+    Sun,
+    Custom
+}
+
+impl FieldSet
+{
+    // the presets that can be picked, custom is a list edited by hand in the settings file
+    pub const PRESETS: [FieldSet; 5] = [FieldSet::Standard, FieldSet::Temperature, FieldSet::Precipitation, FieldSet::Wind, FieldSet::Sun];
+
+    pub fn fields(&self) -> Option< Vec<DailyField> >
+    {
+        return match self
+        {
+            FieldSet::Standard => Some(vec![DailyField::High, DailyField::Low, DailyField::Sunrise, DailyField::Sunset, DailyField::Precipitation, DailyField::Wind]),
+            FieldSet::Temperature => Some(vec![DailyField::High, DailyField::Low, DailyField::FeelsLikeHigh, DailyField::FeelsLikeLow, DailyField::UvIndex, DailyField::Radiation]),
+            FieldSet::Precipitation => Some(vec![DailyField::PrecipitationProbability, DailyField::Precipitation, DailyField::PrecipitationHours, DailyField::Rain, DailyField::Showers, DailyField::Snowfall]),
+            FieldSet::Wind => Some(vec![DailyField::Wind, DailyField::Gusts, DailyField::WindDirection, DailyField::High, DailyField::Low]),
+            FieldSet::Sun => Some(vec![DailyField::Sunrise, DailyField::Sunset, DailyField::Daylight, DailyField::Sunshine, DailyField::UvIndex, DailyField::Radiation]),
+            FieldSet::Custom => None
+        };
+    }
+}
+
+// choices for how often the forecast is re-fetched, in minutes, 0 meaning never
+pub const REFRESH_INTERVALS: [u64; 6] = [0, 5, 15, 30, 60, 180];
+
+// every user editable setting, in the order they are listed on the options screen
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum SettingField
+{
+    UnitSystem,
+    TemperatureUnit,
+    WindUnit,
+    PrecipitationUnit,
+    TimeFormat,
+    DateFormat,
+    Location,
+    ForecastDays,
+    PastDays,
+    RefreshInterval,
+    Theme,
+    Icons,
+    Wind,
+    Fields
+}
+
+impl SettingField
+{
+    pub const ALL: [SettingField; 14] =
+    [
+        SettingField::UnitSystem,
+        SettingField::TemperatureUnit,
+        SettingField::WindUnit,
+        SettingField::PrecipitationUnit,
+        SettingField::TimeFormat,
+        SettingField::DateFormat,
+        SettingField::Location,
+        SettingField::ForecastDays,
+        SettingField::PastDays,
+        SettingField::RefreshInterval,
+        SettingField::Theme,
+        SettingField::Icons,
+        SettingField::Wind,
+        SettingField::Fields
+    ];
+
+    pub fn label(&self) -> &'static str
+    {
+        return match self
+        {
+            SettingField::UnitSystem => "unit system",
+            SettingField::TemperatureUnit => "temperature unit",
+            SettingField::WindUnit => "wind unit",
+            SettingField::PrecipitationUnit => "precipitation unit",
+            SettingField::TimeFormat => "time format",
+            SettingField::DateFormat => "date format",
+            SettingField::Location => "location",
+            SettingField::ForecastDays => "forecast days",
+            SettingField::PastDays => "past days",
+            SettingField::RefreshInterval => "refresh interval",
+            SettingField::Theme => "theme",
+            SettingField::Icons => "icons",
+            SettingField::Wind => "wind",
+            SettingField::Fields => "forecast fields"
+        };
+    }
+
+    // whether changing this field changes what is asked of the api
+    pub fn changes_query(&self) -> bool
+    {
+        return matches!(self, SettingField::UnitSystem | SettingField::TemperatureUnit | SettingField::WindUnit | SettingField::PrecipitationUnit | SettingField::Location | SettingField::ForecastDays | SettingField::PastDays);
+    }
+}
+
+// everything that is persisted between runs
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings
+{
+    pub location: Location,
+    pub units: Units,
+    pub horizon: Horizon,
+    pub time_format: TimeFormat,
+    pub date_format: DateFormat,
+    pub refresh_interval: u64,
+    pub theme: Theme,
+    pub icons: IconStyle,
+    pub wind: WindDisplay,
+    // listed under each day on the forecast screen, in order
+    pub fields: Vec<DailyField>
+}
+
+impl Default for Settings
+{
+    fn default() -> Settings
+    {
+        return Settings
+        {
+            location: Location::default(),
+            units: Units::default(),
+            horizon: Horizon::default(),
+            time_format: TimeFormat::TwentyFourHour,
+            date_format: DateFormat::MonthDayYear,
+            refresh_interval: 30,
+            theme: Theme::Terminal,
+            icons: IconStyle::Emoji,
+            wind: WindDisplay::Speed,
+            fields: FieldSet::Standard.fields().unwrap_or_default()
+        };
+    }
+}
+
+// why a settings file that exists couldn't be used
+#[derive(Debug)]
+pub enum SettingsError
+{
+    Read(io::Error),
+    // the file is there but isn't valid, like a value an older or newer version doesn't know
+    Parse(serde_json::Error)
+}
+
+impl fmt::Display for SettingsError
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter) -> Result<(), fmt::Error>
+    {
+        return match self
+        {
+            SettingsError::Read(e) => fmt.write_fmt(format_args!("couldn't read the settings file: {}", e)),
+            SettingsError::Parse(e) => fmt.write_fmt(format_args!("the settings file is invalid: {}", e))
+        };
+    }
+}
+
+impl std::error::Error for SettingsError {}
+
+// returns the value after (or before) the current one, wrapping around
+fn cycle<T: PartialEq + Copy>(all: &[T], current: T, forward: bool) -> T
+{
+    let index: usize = all.iter().position(|n| *n == current).unwrap_or(0);
+
+    return match forward
+    {
+        true => all[(index + 1) % all.len()],
+        false => all[(index + all.len() - 1) % all.len()]
+    };
+}
+
+impl Settings
+{
+    // where the settings file lives, inside the platform's config directory
+    pub fn path() -> Option<PathBuf>
+    {
+        return dirs::config_dir().map(|dir| dir.join("weatherman").join("settings.json"));
+    }
+
+    // reads a settings file, giving the defaults when there is none yet
+    pub fn load_from(path: &Path) -> Result<Settings, SettingsError>
+    {
+        let contents: String = match fs::read_to_string(path)
+        {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Settings::default()),
+            Err(e) => return Err(SettingsError::Read(e))
+        };
+
+        return serde_json::from_str(&contents).map_err(SettingsError::Parse);
+    }
+
+    // writes a settings file, creating its directory if needed
+    pub fn save_to(&self, path: &Path) -> io::Result<()>
+    {
+        if let Some(dir) = path.parent()
+        {
+            fs::create_dir_all(dir)?;
+        }
+
+        return fs::write(path, serde_json::to_string_pretty(self)?);
+    }
+
+    // the preset the field list matches, or custom when it was edited by hand
+    pub fn field_set(&self) -> FieldSet
+    {
+        return FieldSet::PRESETS.iter()
+            .find(|set| set.fields().as_ref() == Some(&self.fields))
+            .copied()
+            .unwrap_or(FieldSet::Custom);
+    }
+
+    // the current value of a field, as shown on the options screen
+    pub fn value(&self, field: SettingField) -> String
+    {
+        return match field
+        {
+            SettingField::UnitSystem => self.units.system().to_string(),
+            SettingField::TemperatureUnit => self.units.temperature.suffix().to_string(),
+            SettingField::WindUnit => self.units.wind.suffix().to_string(),
+            SettingField::PrecipitationUnit => self.units.precipitation.suffix().to_string(),
+            SettingField::TimeFormat => self.time_format.format(NaiveTime::from_hms_opt(18, 30, 0).unwrap_or_default()),
+            SettingField::DateFormat => self.date_format.format(NaiveDate::from_ymd_opt(2023, 1, 31).unwrap_or_default()),
+            SettingField::Location => self.location.to_string(),
+            SettingField::ForecastDays => format!("{} days", self.horizon.forecast_days),
+            SettingField::PastDays => format!("{} days", self.horizon.past_days),
+            SettingField::RefreshInterval => match self.refresh_interval
+            {
+                0 => String::from("never"),
+                minutes => format!("{} minutes", minutes)
+            },
+            SettingField::Theme => format!("{:?}", self.theme).to_lowercase(),
+            SettingField::Icons => format!("{:?}", self.icons).to_lowercase(),
+            SettingField::Wind => format!("{:?}", self.wind).to_lowercase(),
+            SettingField::Fields => format!("{:?}", self.field_set()).to_lowercase()
+        };
+    }
+
+    // steps a field to its next (or previous) value, returning whether anything changed
+    pub fn cycle(&mut self, field: SettingField, forward: bool) -> bool
+    {
+        let before: Settings = self.clone();
+
+        match field
+        {
+            // stepping from custom units starts over at the first preset
+            SettingField::UnitSystem =>
+            {
+                let system: UnitSystem = match self.units.system()
+                {
+                    UnitSystem::Custom => UnitSystem::PRESETS[0],
+                    system => cycle(&UnitSystem::PRESETS, system, forward)
+                };
+
+                self.units = system.units().unwrap_or(self.units);
+            },
+            SettingField::TemperatureUnit => self.units.temperature = cycle(&TemperatureUnit::ALL, self.units.temperature, forward),
+            SettingField::WindUnit => self.units.wind = cycle(&WindUnit::ALL, self.units.wind, forward),
+            SettingField::PrecipitationUnit => self.units.precipitation = cycle(&PrecipitationUnit::ALL, self.units.precipitation, forward),
+            SettingField::TimeFormat => self.time_format = cycle(&TimeFormat::ALL, self.time_format, forward),
+            SettingField::DateFormat => self.date_format = cycle(&DateFormat::ALL, self.date_format, forward),
+            SettingField::ForecastDays => self.horizon.forecast_days = cycle(&Horizon::FORECAST_DAYS, self.horizon.forecast_days, forward),
+            SettingField::PastDays => self.horizon.past_days = cycle(&Horizon::PAST_DAYS, self.horizon.past_days, forward),
+            SettingField::RefreshInterval => self.refresh_interval = cycle(&REFRESH_INTERVALS, self.refresh_interval, forward),
+            SettingField::Theme => self.theme = cycle(&Theme::ALL, self.theme, forward),
+            SettingField::Icons => self.icons = cycle(&IconStyle::ALL, self.icons, forward),
+            SettingField::Wind => self.wind = cycle(&WindDisplay::ALL, self.wind, forward),
+            // like the unit system, stepping from a custom list starts over at the first preset
+            SettingField::Fields =>
+            {
+                let set: FieldSet = match self.field_set()
+                {
+                    FieldSet::Custom => FieldSet::PRESETS[0],
+                    set => cycle(&FieldSet::PRESETS, set, forward)
+                };
+
+                self.fields = set.fields().unwrap_or(self.fields.clone());
+            },
+            // the location is picked on the search screen instead
+            SettingField::Location => {}
+        }
+
+        return *self != before;
+    }
+
+    // takes one field over from other settings, leaving the rest as they are
+    pub fn copy_field(&mut self, other: &Settings, field: SettingField)
+    {
+        match field
+        {
+            SettingField::UnitSystem => self.units = other.units,
+            SettingField::TemperatureUnit => self.units.temperature = other.units.temperature,
+            SettingField::WindUnit => self.units.wind = other.units.wind,
+            SettingField::PrecipitationUnit => self.units.precipitation = other.units.precipitation,
+            SettingField::TimeFormat => self.time_format = other.time_format,
+            SettingField::DateFormat => self.date_format = other.date_format,
+            SettingField::Location => self.location = other.location.clone(),
+            SettingField::ForecastDays => self.horizon.forecast_days = other.horizon.forecast_days,
+            SettingField::PastDays => self.horizon.past_days = other.horizon.past_days,
+            SettingField::RefreshInterval => self.refresh_interval = other.refresh_interval,
+            SettingField::Theme => self.theme = other.theme,
+            SettingField::Icons => self.icons = other.icons,
+            SettingField::Wind => self.wind = other.wind,
+            SettingField::Fields => self.fields = other.fields.clone()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+
+    #[test]
+    fn cycles_through_values_and_reports_whether_one_changed()
+    {
+        let mut settings: Settings = Settings::default();
+
+        assert!(settings.cycle(SettingField::Theme, true));
+        assert!(settings.cycle(SettingField::Theme, false));
+        assert_eq!(settings.theme, Settings::default().theme);
+
+        // the location is only ever picked on the search screen
+        for field in SettingField::ALL.iter().filter(|field| **field != SettingField::Location)
+        {
+            assert!(settings.cycle(*field, true), "{} didn't change", field.label());
+        }
+
+        assert!(!settings.cycle(SettingField::Location, true));
+        assert!(!settings.cycle(SettingField::Location, false));
+    }
+
+    #[test]
+    fn copies_one_field_and_leaves_the_rest()
+    {
+        let mut changed: Settings = Settings::default();
+        changed.cycle(SettingField::TemperatureUnit, true);
+        changed.cycle(SettingField::Theme, true);
+
+        let mut settings: Settings = Settings::default();
+        settings.copy_field(&changed, SettingField::Theme);
+
+        assert_eq!(settings.theme, changed.theme);
+        assert_eq!(settings.units, Settings::default().units);
+    }
+
+    #[test]
+    fn names_the_preset_the_fields_match()
+    {
+        let mut settings: Settings = Settings::default();
+        assert_eq!(settings.field_set(), FieldSet::Standard);
+
+        settings.fields.pop();
+        assert_eq!(settings.field_set(), FieldSet::Custom);
+
+        // stepping from a custom list starts over at the first preset
+        settings.cycle(SettingField::Fields, true);
+        assert_eq!(settings.field_set(), FieldSet::PRESETS[0]);
+    }
+
+    #[test]
+    fn loads_what_was_saved_and_reports_a_broken_file()
+    {
+        let path: PathBuf = std::env::temp_dir().join(format!("weatherman-settings-{}.json", std::process::id()));
+
+        // a missing file is a first run rather than an error
+        assert_eq!(Settings::load_from(&path).unwrap(), Settings::default());
+
+        let mut settings: Settings = Settings::default();
+        settings.cycle(SettingField::Wind, true);
+        settings.cycle(SettingField::PastDays, true);
+        settings.save_to(&path).unwrap();
+        assert_eq!(Settings::load_from(&path).unwrap(), settings);
+
+        fs::write(&path, r#"{ "theme": "Plaid" }"#).unwrap();
+        assert!(matches!(Settings::load_from(&path), Err(SettingsError::Parse(_))));
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,86 @@
+use std::{io, panic, ops::{Deref, DerefMut}};
+use tui::{backend::CrosstermBackend, Terminal};
+use crossterm::
+{
+    execute,
+    cursor::{Hide, Show},
+    event::{EnableMouseCapture, DisableMouseCapture},
+    terminal::{enable_raw_mode, disable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen}
+};
+
+// the terminal the ui draws on, put back the way it was found when dropped
+pub struct TerminalGuard
+{
+    terminal: Terminal<CrosstermBackend<io::Stdout>>
+}
+
+impl TerminalGuard
+{
+    // switches to raw mode and the alternate screen with the cursor hidden and mouse events captured, making sure a panic undoes it before its message is printed
+    pub fn new() -> io::Result<TerminalGuard>
+    {
+        install_panic_hook();
+
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen, EnableMouseCapture, Hide)?;
+
+        let mut terminal: Terminal<CrosstermBackend<io::Stdout>> = Terminal::new(CrosstermBackend::new(io::stdout()))?;
+        terminal.clear()?;
+
+        return Ok(TerminalGuard { terminal });
+    }
+}
+
+impl Deref for TerminalGuard
+{
+    type Target = Terminal<CrosstermBackend<io::Stdout>>;
+
+    fn deref(&self) -> &Self::Target
+    {
+        return &self.terminal;
+    }
+}
+
+impl DerefMut for TerminalGuard
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        return &mut self.terminal;
+    }
+}
+
+impl Drop for TerminalGuard
+{
+    fn drop(&mut self)
+    {
+        // nothing left to report a failure to
+        let _ = restore();
+    }
+}
+
+// leaves raw mode and the alternate screen, stops capturing the mouse and shows the cursor again, safe to call more than once
+pub fn restore() -> io::Result<()>
+{
+    disable_raw_mode()?;
+    execute!(io::stdout(), DisableMouseCapture, LeaveAlternateScreen, Show)?;
+
+    return Ok(());
+}
+
+// restores the terminal before the default hook prints the panic, so the message lands in the normal shell
+fn install_panic_hook()
+{
+    let default_hook = panic::take_hook();
+
+    panic::set_hook
+    (
+        Box::new
+        (
+            move |info|
+            {
+                let _ = restore();
+                default_hook(info);
+            }
+        )
+    );
+}
@@ -1,19 +1,51 @@
+use tui::{backend::Backend, Frame};
+use crossterm::event::KeyEvent;
+
+use crate::app::{App, Action};
+
 // keeping track of which screen the user is on
+#[derive(Clone, Copy, Debug, PartialEq)]
 pub enum Screen
 {
     FORECAST,
     OPTIONS,
     HOURLY,
+    SEARCH,
+    ERROR,
     MENU,
+    DAY,
+    LOADING,
+}
+
+// what every screen provides so the app can draw it and pass it key presses without knowing which one it is
+pub trait ScreenView
+{
+    // draws the screen from the app's state, which stateful widgets may scroll
+    fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>);
+
+    // updates the app's state for a key press, returning whatever the main loop has to do about it
+    fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action;
+}
+
+// returns a borderless block for filling the whole screen with the theme's colors before drawing anything else
+pub fn get_background<'a>(theme: &crate::settings::Theme) -> tui::widgets::Block<'a>
+{
+    return tui::widgets::Block::default()
+        .style(theme.base_style());
 }
 
 pub mod loading_screen
 {
     use tui::
     {
+        backend::Backend, Frame,
         widgets::{Block, Borders, BorderType, Paragraph},
-        layout::{Layout, Direction, Constraint, Rect, Alignment}
+        layout::{Layout, Direction, Constraint, Rect, Alignment},
+        style::{Style, Color}
     };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::ScreenView;
 
     // given its anchor area, returns a rect in the middle of the screen for other elements to go in
     pub fn get_loading_panel(area: Rect) -> Rect
@@ -45,10 +77,40 @@ pub mod loading_screen
         return rows[2];
     }
     
+    // frames of the spinner shown while a refresh is in flight
+    const SPINNER_FRAMES: [&str; 10] = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+    // given its anchor area, returns a small rect in the top right corner for the spinner to sit over whatever is underneath
+    pub fn get_spinner_panel(area: Rect) -> Rect
+    {
+        let width: u16 = area.width.min(18);
+        let height: u16 = area.height.min(3);
+
+        return Rect::new(area.x + area.width - width, area.y, width, height);
+    }
+
+    // returns the spinner paragraph for the given animation step
+    pub fn get_spinner<'a>(frame: usize) -> Paragraph<'a>
+    {
+        return Paragraph::new(format!("{} refreshing", SPINNER_FRAMES[frame % SPINNER_FRAMES.len()]))
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::Yellow)
+            )
+            .alignment(Alignment::Center);
+    }
+
     // returns the loading paragraph
     pub fn get_loading_message<'a>() -> Paragraph<'a>
     {
-        return Paragraph::new("Loading...")
+        return Paragraph::new("Loading... (q to quit)")
             .block
             (
                 Block::default()
@@ -57,18 +119,323 @@ pub mod loading_screen
             )
             .alignment(Alignment::Center)
     }
+
+    // shown while waiting on a forecast with nothing else to show, input still works so it can be left
+    pub struct LoadingView;
+
+    impl ScreenView for LoadingView
+    {
+        fn render<B: Backend>(&self, _app: &mut App, frame: &mut Frame<B>)
+        {
+            frame.render_widget(get_loading_message(), get_loading_panel(frame.size()));
+        }
+
+        fn handle_key(&self, _app: &mut App, key: KeyEvent) -> Action
+        {
+            return match key.code
+            {
+                KeyCode::Char('q') => Action::Quit,
+                _ => Action::None
+            };
+        }
+    }
 }
 
-pub mod forecast_screen
+pub mod error_screen
+{
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Paragraph, Wrap},
+        layout::{Layout, Direction, Constraint, Rect, Alignment},
+        text::{Spans, Span}, style::{Style, Modifier, Color}
+    };
+
+    use crate::weather::WeatherError;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
+
+    // returns the layout for the error screen: the reason with the controls below it
+    pub fn get_error_panel(area: Rect) -> Vec<Rect>
+    {
+        let cols: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints
+            ([
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20)
+            ])
+            .split(area);
+
+        return Layout::default()
+            .direction(Direction::Vertical)
+            .constraints
+            ([
+                Constraint::Percentage(30),
+                Constraint::Min(5),             // reason
+                Constraint::Length(3),          // controls
+                Constraint::Percentage(30)
+            ])
+            .split(cols[1]);
+    }
+
+    // returns the paragraph explaining what went wrong
+    pub fn get_error_message<'a>(error: &WeatherError) -> Paragraph<'a>
+    {
+        return Paragraph::new(error.to_string())
+            .block
+            (
+                Block::default()
+                    .title("couldn't load the forecast")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::LightRed)
+            )
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true });
+    }
+
+    // returns a paragraph stating the controls
+    pub fn get_error_controls<'a>() -> Paragraph<'a>
+    {
+        return Paragraph::new
+            (
+                Spans::from
+                (vec![
+                    Span::styled("R", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": retry | "),
+                    Span::styled("M", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": menu | "),
+                    Span::styled("Q", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": quit ")
+                ])
+            )
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::White)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // shown when a forecast the user waited on couldn't be fetched
+    pub struct ErrorView;
+
+    impl ScreenView for ErrorView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            let error_panel: Vec<Rect> = get_error_panel(frame.size());
+
+            if let Some(error) = &app.fetch_error
+            {
+                frame.render_widget(get_error_message(error), error_panel[1]);
+            }
+
+            frame.render_widget(get_error_controls(), error_panel[2]);
+        }
+
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            match key.code
+            {
+                KeyCode::Char('q') => return Action::Quit,
+                KeyCode::Char('m') => app.screen = Screen::MENU,
+                KeyCode::Char('r') => return Action::Fetch,
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
+}
+
+pub mod weather_art
 {
     use tui::
     {
         widgets::{Block, Borders, BorderType, Paragraph},
+        layout::{Rect, Alignment},
+        text::{Spans, Span}, style::{Style, Color}
+    };
+
+    use crate::weather::WeatherCondition;
+    use crate::settings::Settings;
+
+    // every piece of art is this many columns wide and lines tall
+    pub const ART_WIDTH: u16 = 13;
+    pub const ART_HEIGHT: u16 = 5;
+
+    // multi-line drawings of each condition, in the style of wttr.in
+    pub fn get_art(condition: &WeatherCondition) -> [&'static str; ART_HEIGHT as usize]
+    {
+        return match condition
+        {
+            WeatherCondition::Clear =>
+            [
+                "    \\   /    ",
+                "     .-.     ",
+                "  ― (   ) ―  ",
+                "     `-’     ",
+                "    /   \\    "
+            ],
+            WeatherCondition::PartlyCloudy =>
+            [
+                "   \\  /      ",
+                " _ /\"\".-.    ",
+                "   \\_(   ).  ",
+                "   /(___(__) ",
+                "             "
+            ],
+            WeatherCondition::Cloudy =>
+            [
+                "             ",
+                "     .--.    ",
+                "  .-(    ).  ",
+                " (___.__)__) ",
+                "             "
+            ],
+            WeatherCondition::Fog =>
+            [
+                "             ",
+                " _ - _ - _ - ",
+                "  _ - _ - _  ",
+                " _ - _ - _ - ",
+                "             "
+            ],
+            WeatherCondition::Drizzle =>
+            [
+                "     .-.     ",
+                "    (   ).   ",
+                "   (___(__)  ",
+                "    ‘ ‘ ‘ ‘  ",
+                "   ‘ ‘ ‘ ‘   "
+            ],
+            WeatherCondition::Rain =>
+            [
+                "     .-.     ",
+                "    (   ).   ",
+                "   (___(__)  ",
+                "  ‚‘‚‘‚‘‚‘   ",
+                "  ‚’‚’‚’‚’   "
+            ],
+            WeatherCondition::FreezingRain =>
+            [
+                "     .-.     ",
+                "    (   ).   ",
+                "   (___(__)  ",
+                "    ‘ * ‘ *  ",
+                "   * ‘ * ‘   "
+            ],
+            WeatherCondition::Snow =>
+            [
+                "     .-.     ",
+                "    (   ).   ",
+                "   (___(__)  ",
+                "    *  *  *  ",
+                "   *  *  *   "
+            ],
+            WeatherCondition::Showers =>
+            [
+                " _`/\"\".-.    ",
+                "  ,\\_(   ).  ",
+                "   /(___(__) ",
+                "     ‘ ‘ ‘ ‘ ",
+                "    ‘ ‘ ‘ ‘  "
+            ],
+            WeatherCondition::Thunderstorm =>
+            [
+                "     .-.     ",
+                "    (   ).   ",
+                "   (___(__)  ",
+                "  ‚‘ϟ‚‘ϟ‚‘   ",
+                "  ‚’‚’ϟ’‚’   "
+            ],
+            WeatherCondition::Hail =>
+            [
+                "     .-.     ",
+                "    (   ).   ",
+                "   (___(__)  ",
+                "   ‚ o ϟ o   ",
+                "   o ‚ o ‚   "
+            ]
+        };
+    }
+
+    // sun is yellow, clouds and fog grey, rain blue, snow and ice white, storms purple
+    pub fn get_art_color(condition: &WeatherCondition) -> Color
+    {
+        return match condition
+        {
+            WeatherCondition::Clear => Color::Yellow,
+            WeatherCondition::PartlyCloudy | WeatherCondition::Showers => Color::LightYellow,
+            WeatherCondition::Cloudy | WeatherCondition::Fog => Color::Gray,
+            WeatherCondition::Drizzle | WeatherCondition::Rain => Color::LightBlue,
+            WeatherCondition::FreezingRain | WeatherCondition::Snow | WeatherCondition::Hail => Color::White,
+            WeatherCondition::Thunderstorm => Color::LightMagenta
+        };
+    }
+
+    // whether the full art fits inside the given area once its side borders are drawn
+    pub fn art_fits(area: Rect) -> bool
+    {
+        return area.width >= ART_WIDTH + 2 && area.height >= ART_HEIGHT;
+    }
+
+    // returns the art for a condition, or just its icon when the area is too small for the art
+    pub fn get_art_paragraph<'a>(condition: &Option<WeatherCondition>, area: Rect, border: Borders, settings: &Settings) -> Paragraph<'a>
+    {
+        let lines: Vec<Spans> = match condition
+        {
+            Some(condition) if art_fits(area) =>
+            {
+                // padding above the art so it sits in the middle of its slot
+                let padding: usize = ((area.height - ART_HEIGHT) / 2) as usize;
+
+                std::iter::repeat_n(Spans::from(""), padding)
+                    .chain(get_art(condition).iter().map(|line| Spans::from(Span::styled(*line, Style::default().fg(get_art_color(condition))))))
+                    .collect()
+            },
+            Some(condition) => vec![Spans::from(Span::styled(settings.icons.icon(condition), Style::default().fg(get_art_color(condition))))],
+            None => vec![Spans::from("")]
+        };
+
+        return Paragraph::new(lines)
+            .block(Block::default().borders(border).border_type(BorderType::Plain))
+            .alignment(Alignment::Center);
+    }
+}
+
+pub mod forecast_screen
+{
+    use std::ops::Range;
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Paragraph, Table, Row, Cell, Clear},
         layout::{Layout, Direction, Constraint, Rect, Alignment},
         text::{Spans, Span}, style::{Style, Modifier, Color}
     };
-    
-    use crate::weather::DailyWeather;
+    use chrono::{Duration, Utc};
+
+    use crate::weather::{CurrentWeather, DailyWeather, HourlyForecast, Location, WeeklyForecast, display_measurement, display_duration, display_direction};
+    use crate::settings::{DailyField, Settings};
+    use super::{weather_art, chart_view, loading_screen};
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
 
     // how to let each forecast slot know where it is in the set
     #[derive(PartialEq)]
@@ -79,19 +446,75 @@ pub mod forecast_screen
         RIGHT
     }
     
-    // ease of use for converting index to a position
+    // ease of use for converting index to a position, the middle slot is the one drawn with both borders
     impl ForecastPosition
     {
-        pub fn from_index(index: &usize) -> ForecastPosition
+        pub fn from_index(index: &usize, count: &usize) -> ForecastPosition
         {
+            let middle: usize = count / 2;
+
             return match index
             {
-                0..=2 => ForecastPosition::LEFT,
-                3 => ForecastPosition::MIDDLE,
+                i if *i < middle => ForecastPosition::LEFT,
+                i if *i == middle => ForecastPosition::MIDDLE,
                 _ => ForecastPosition::RIGHT
             };
         }
     }
+
+    // narrowest a day's column can be before its text stops fitting
+    pub const SLOT_MIN_WIDTH: u16 = 16;
+
+    // lines a day's column needs besides its fields: the date, weekday, condition and at least one for the art
+    pub const SLOT_BASE_HEIGHT: u16 = 6;
+
+    // fewest days the carousel shows at once before falling back to the list
+    pub const CAROUSEL_DAYS: usize = 3;
+
+    // the ways the days can be laid out, picked by how much room there is
+    #[derive(Clone, Copy, PartialEq)]
+    pub enum ForecastLayout
+    {
+        Columns,
+        Carousel,
+        List
+    }
+
+    impl ForecastLayout
+    {
+        // every day side by side if they all fit, as many as fit at a time if that's a few, otherwise a row per day
+        pub fn from_area(area: Rect, days: usize, fields: usize) -> ForecastLayout
+        {
+            if area.height < SLOT_BASE_HEIGHT + fields as u16
+            {
+                return ForecastLayout::List;
+            }
+
+            return match area.width / SLOT_MIN_WIDTH
+            {
+                fits if fits as usize >= days => ForecastLayout::Columns,
+                fits if fits as usize >= CAROUSEL_DAYS => ForecastLayout::Carousel,
+                _ => ForecastLayout::List
+            };
+        }
+
+        // the days to draw as columns in the area, keeping the focused day in view
+        pub fn visible_range(&self, focused: usize, days: usize, area: Rect) -> Range<usize>
+        {
+            return match self
+            {
+                ForecastLayout::Carousel =>
+                {
+                    let count: usize = ((area.width / SLOT_MIN_WIDTH) as usize).clamp(1, days.max(1));
+                    let start: usize = focused.saturating_sub(count / 2).min(days.saturating_sub(count));
+
+                    start..(start + count).min(days)
+                },
+                _ => 0..days
+            };
+        }
+    }
+
     // given the area to place it, returns the layout for the home screen
     pub fn get_forecast_panel(area: Rect) -> Vec<Rect>
     {
@@ -99,18 +522,18 @@ pub mod forecast_screen
             .direction(Direction::Vertical)
             .constraints
             ([
-                Constraint::Percentage(9),      // title
-                Constraint::Percentage(9),      // title
-                Constraint::Percentage(73),     // body
-                Constraint::Percentage(9)       // controls
+                Constraint::Length(3),          // title
+                Constraint::Length(3),          // current conditions
+                Constraint::Min(0),             // body
+                Constraint::Length(3)           // controls
             ])
             .split(area);
     }
     
-    // returns the title paragraph
-    pub fn get_forecast_title<'a>() -> Paragraph<'a>
+    // returns the title paragraph showing which location the forecast is for
+    pub fn get_forecast_title<'a>(location: &Location) -> Paragraph<'a>
     {
-        return Paragraph::new("weatherman 🌩️")
+        return Paragraph::new(format!("weatherman 🌩️ | {}", location))
             .block
             (
                 Block::default()
@@ -125,6 +548,41 @@ pub mod forecast_screen
             .alignment(Alignment::Center);
     }
     
+    // returns the panel with the conditions right now, borrowing the hourly units since they are requested the same way
+    pub fn get_current_paragraph<'a>(current: &Option<CurrentWeather>, hourly: &HourlyForecast, settings: &Settings) -> Paragraph<'a>
+    {
+        let spans: Spans = match current
+        {
+            Some(current) => Spans::from
+            (vec![
+                Span::styled("now ", Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("{}{}", current.temp, hourly.unit("temperature_2m")), Style::default().fg(Color::LightRed).add_modifier(Modifier::BOLD)),
+                Span::raw(" | "),
+                Span::styled(settings.icons.describe(&current.condition), Style::default().fg(Color::White)),
+                Span::raw(" | "),
+                Span::styled(format!("winds {}", settings.wind.format(&Some(current.windspeed), &hourly.unit("windspeed_10m"), &Some(current.winddirection))), Style::default().fg(Color::White)),
+                Span::raw(" | "),
+                match current.is_day
+                {
+                    true => Span::styled("☀ day", Style::default().fg(Color::Yellow)),
+                    false => Span::styled("☾ night", Style::default().fg(Color::DarkGray))
+                },
+                Span::styled(format!(" as of {}", settings.time_format.format(current.time.time())), Style::default().fg(Color::DarkGray))
+            ]),
+            None => Spans::from(Span::styled("current conditions unavailable", Style::default().fg(Color::DarkGray)))
+        };
+
+        return Paragraph::new(spans)
+            .block
+            (
+                Block::default()
+                    .title("right now")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .alignment(Alignment::Center);
+    }
+
     // returns the tabs (for now not much)
     pub fn get_forecast_tabs<'a>() -> Paragraph<'a>
     {
@@ -143,47 +601,84 @@ pub mod forecast_screen
             .alignment(Alignment::Center);
     }
     
-    // returns the layout for the 7 forecast colummns
-    pub fn get_forecast_slot_panel(area: Rect) -> Vec<Rect>
+    // returns the layout for however many forecast columns are shown
+    pub fn get_forecast_slot_panel(area: Rect, count: usize) -> Vec<Rect>
     {
         return Layout::default()
             .direction(Direction::Horizontal)
-            .constraints
-            ([
-                Constraint::Percentage(15),
-                Constraint::Percentage(14),
-                Constraint::Percentage(14),
-                Constraint::Percentage(14),
-                Constraint::Percentage(14),
-                Constraint::Percentage(14),
-                Constraint::Percentage(15),
-            ])
+            .constraints(vec![Constraint::Ratio(1, count.max(1) as u32); count])
             .split(area);
     }
     
-    // returns the layout for each individual forecast column
-    pub fn get_forecast_slot_layout(area: Rect) -> Vec<Rect>
+    // returns the layout for each individual forecast column with a line per field, the art takes whatever height is left over
+    pub fn get_forecast_slot_layout(area: Rect, fields: usize) -> Vec<Rect>
     {
+        let mut constraints: Vec<Constraint> = vec!
+        [
+            Constraint::Length(3),          // date
+            Constraint::Length(1),          // weekday
+            Constraint::Min(1),             // art
+            Constraint::Length(1)           // condition
+        ];
+
+        constraints.extend(std::iter::repeat_n(Constraint::Length(1), fields));
+
         return Layout::default()
             .direction(Direction::Vertical)
-            .constraints
-            ([
-                Constraint::Percentage(14),
-                Constraint::Percentage(6),
-                Constraint::Percentage(14),
-                Constraint::Percentage(14),
-                Constraint::Percentage(13),
-                Constraint::Percentage(13),
-                Constraint::Percentage(13),
-                Constraint::Percentage(13)
-            ])
+            .constraints(constraints)
             .split(area);
     }
-    
-    // given the forecast day and its position relative to the other 6, returns a vec of paragraphs with all the notable information
-    pub fn get_forecast_paragraphs<'a>(day: &DailyWeather, pos: ForecastPosition) -> Vec< Paragraph<'a> >
+
+    // formats one of a day's fields with its unit, per the settings
+    pub fn display_field(field: &DailyField, day: &DailyWeather, forecast: &WeeklyForecast, settings: &Settings) -> String
+    {
+        let unit: String = field.variable().map_or(String::new(), |variable| forecast.unit(variable));
+
+        return match field
+        {
+            DailyField::High => display_measurement(&day.max_temp, &unit),
+            DailyField::Low => display_measurement(&day.min_temp, &unit),
+            DailyField::FeelsLikeHigh => display_measurement(&day.apparent_max_temp, &unit),
+            DailyField::FeelsLikeLow => display_measurement(&day.apparent_min_temp, &unit),
+            DailyField::Sunrise => day.sunrise.map_or(String::from("--"), |n| settings.time_format.format(n.time())),
+            DailyField::Sunset => day.sunset.map_or(String::from("--"), |n| settings.time_format.format(n.time())),
+            DailyField::Daylight => day.daylight().map_or(String::from("--"), |n| display_duration(&n)),
+            DailyField::Sunshine => day.sunshine.map_or(String::from("--"), |n| display_duration(&n)),
+            DailyField::UvIndex => display_measurement(&day.uv_index, &unit),
+            DailyField::Radiation => display_measurement(&day.radiation, &unit),
+            DailyField::Precipitation => display_measurement(&day.precipitation, &unit),
+            DailyField::PrecipitationProbability => display_measurement(&day.precipitation_probability, &unit),
+            DailyField::PrecipitationHours => display_measurement(&day.precipitation_hours, &unit),
+            DailyField::Rain => display_measurement(&day.rain, &unit),
+            DailyField::Showers => display_measurement(&day.showers, &unit),
+            DailyField::Snowfall => display_measurement(&day.snowfall, &unit),
+            DailyField::Wind => settings.wind.format(&day.max_windspeed, &unit, &day.wind_direction),
+            DailyField::Gusts => settings.wind.format(&day.max_windgusts, &unit, &None),
+            DailyField::WindDirection => display_direction(&day.wind_direction)
+        };
+    }
+
+    // warm temperatures red, cool ones cyan, the sun yellow, water blue
+    pub fn get_field_color(field: &DailyField) -> Color
+    {
+        return match field
+        {
+            DailyField::High | DailyField::FeelsLikeHigh => Color::LightRed,
+            DailyField::Low | DailyField::FeelsLikeLow => Color::Cyan,
+            DailyField::Sunrise | DailyField::Daylight | DailyField::Sunshine => Color::Yellow,
+            DailyField::Sunset => Color::DarkGray,
+            DailyField::UvIndex | DailyField::Radiation => Color::LightMagenta,
+            DailyField::Precipitation | DailyField::PrecipitationProbability | DailyField::PrecipitationHours | DailyField::Rain | DailyField::Showers => Color::Blue,
+            DailyField::Snowfall | DailyField::Wind | DailyField::Gusts | DailyField::WindDirection => Color::White
+        };
+    }
+
+    // given the forecast day and its position relative to the other columns, returns a vec of paragraphs with all the notable information formatted per the settings
+    // unit suffixes come from the forecast the day belongs to so they always match the numbers
+    // the art is only drawn in full when its slot is big enough to hold it, and the selected day's date is highlighted
+    pub fn get_forecast_paragraphs<'a>(day: &DailyWeather, pos: ForecastPosition, art_area: Rect, selected: bool, forecast: &WeeklyForecast, settings: &Settings) -> Vec< Paragraph<'a> >
     {
-        let mut paragraphs: Vec< Paragraph<'a> > = Vec::with_capacity(7);
+        let mut paragraphs: Vec< Paragraph<'a> > = Vec::with_capacity(4 + settings.fields.len());
     
         let border: Borders = match pos
         {
@@ -192,67 +687,873 @@ pub mod forecast_screen
             ForecastPosition::RIGHT => Borders::RIGHT
         };
     
-        let date_clone: String = day.date.clone();
-        let split_date: Vec<&str> = date_clone.split("-").collect();
-        paragraphs.push(Paragraph::new(Span::styled(format!("{}/{}/{}", split_date[1], split_date[2], split_date[0]), Style::default().add_modifier(Modifier::UNDERLINED).add_modifier(Modifier::BOLD)))
-            .block(Block::default().borders(Borders::ALL).border_type(BorderType::Rounded))
-            .style(Style::default().fg(Color::Magenta))
-            .alignment(Alignment::Center));
-    
-        paragraphs.push(Paragraph::new(""));
-    
-        paragraphs.push(Paragraph::new(Span::styled(format!("high: {}°F", day.max_temp), Style::default().fg(Color::LightRed)))
-            .block(Block::default().borders(border.clone()).border_type(BorderType::Plain))
-            .alignment(Alignment::Center));
-    
-        paragraphs.push(Paragraph::new(Span::styled(format!("low: {}°F", day.min_temp), Style::default().fg(Color::Cyan)))
-            .block(Block::default().borders(border.clone()).border_type(BorderType::Plain))
-            .alignment(Alignment::Center));
-    
-        paragraphs.push(Paragraph::new(Span::styled(format!("sunrise: {}", day.sunrise), Style::default().fg(Color::Yellow)))
-            .block(Block::default().borders(border.clone()).border_type(BorderType::Plain))
+        // today's column stands out from the rest
+        let date_color: Color = match forecast.is_today(day)
+        {
+            true => Color::LightGreen,
+            false => Color::Magenta
+        };
+
+        let (date_style, date_border): (Style, BorderType) = match selected
+        {
+            true => (Style::default().add_modifier(Modifier::REVERSED).add_modifier(Modifier::BOLD), BorderType::Thick),
+            false => (Style::default().add_modifier(Modifier::UNDERLINED).add_modifier(Modifier::BOLD), BorderType::Rounded)
+        };
+
+        paragraphs.push(Paragraph::new(Span::styled(settings.date_format.format(day.date), date_style))
+            .block(Block::default().borders(Borders::ALL).border_type(date_border))
+            .style(Style::default().fg(date_color))
             .alignment(Alignment::Center));
     
-        paragraphs.push(Paragraph::new(Span::styled(format!("sunset: {}", day.sunset), Style::default().fg(Color::DarkGray)))
-            .block(Block::default().borders(border.clone()).border_type(BorderType::Plain))
+        paragraphs.push(Paragraph::new(Span::styled(day.date.format("%A").to_string(), Style::default().fg(date_color)))
             .alignment(Alignment::Center));
-    
-        paragraphs.push(Paragraph::new(Span::styled(format!("precip: {}in", day.precipitation), Style::default().fg(Color::Blue)))
-            .block(Block::default().borders(border.clone()).border_type(BorderType::Plain))
+
+        paragraphs.push(weather_art::get_art_paragraph(&day.condition, art_area, border, settings));
+
+        paragraphs.push(Paragraph::new(Span::styled(settings.icons.describe(&day.condition), Style::default().fg(Color::White).add_modifier(Modifier::BOLD)))
+            .block(Block::default().borders(border).border_type(BorderType::Plain))
             .alignment(Alignment::Center));
     
-        paragraphs.push(Paragraph::new(Span::styled(format!("winds: {}mph", day.max_windspeed), Style::default().fg(Color::White)))
-            .block(Block::default().borders(border.clone()).border_type(BorderType::Plain))
-            .alignment(Alignment::Center));
+        for field in settings.fields.iter()
+        {
+            paragraphs.push(Paragraph::new(Span::styled(format!("{}: {}", field.short_label(), display_field(field, day, forecast, settings)), Style::default().fg(get_field_color(field))))
+                .block(Block::default().borders(border).border_type(BorderType::Plain))
+                .alignment(Alignment::Center));
+        }
     
         return paragraphs;
     }
     
-    // returns a paragraph stating the controls
-    pub fn get_forecast_controls<'a>() -> Paragraph<'a>
+    // the date, weekday and condition columns of the table, then room for every field
+    static TABLE_WIDTHS: [Constraint; 3 + DailyField::ALL.len()] =
     {
-        return Paragraph::new
+        let mut widths: [Constraint; 3 + DailyField::ALL.len()] = [Constraint::Length(10); 3 + DailyField::ALL.len()];
+        widths[1] = Constraint::Length(3);
+        widths[2] = Constraint::Length(16);
+        widths
+    };
+
+    // returns a table with a row per day and a column per field for when there is no room for columns, the selected row is scrolled into view when rendered with its state
+    pub fn get_forecast_table<'a>(forecast: &WeeklyForecast, settings: &Settings) -> Table<'a>
+    {
+        let header: Row = Row::new(["date", "day", "conditions"].into_iter().chain(settings.fields.iter().map(|field| field.short_label())))
+            .style(Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED));
+
+        let rows: Vec<Row> = forecast.days.iter()
+            .map
             (
-                Spans::from
-                (vec![
-                    Span::styled("Q", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
-                    Span::raw(": quit | "),
-                    Span::styled("M", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
-                    Span::raw(": menu ")
-                ])
+                |day|
+                {
+                    let date_color: Color = match forecast.is_today(day)
+                    {
+                        true => Color::LightGreen,
+                        false => Color::Magenta
+                    };
+
+                    let mut cells: Vec<Cell> = vec!
+                    [
+                        Cell::from(settings.date_format.format(day.date)).style(Style::default().fg(date_color)),
+                        Cell::from(day.date.format("%a").to_string()).style(Style::default().fg(date_color)),
+                        Cell::from(settings.icons.describe(&day.condition))
+                    ];
+
+                    cells.extend(settings.fields.iter().map(|field| Cell::from(display_field(field, day, forecast, settings)).style(Style::default().fg(get_field_color(field)))));
+
+                    Row::new(cells)
+                }
             )
+            .collect();
+
+        return Table::new(rows)
+            .header(header)
             .block
             (
                 Block::default()
                     .borders(Borders::ALL)
                     .border_type(BorderType::Rounded)
             )
-            .style
-            (
-                Style::default()
-                    .fg(Color::White)
+            .widths(&TABLE_WIDTHS[..(3 + settings.fields.len()).min(TABLE_WIDTHS.len())])
+            .column_spacing(1)
+            .highlight_style(Style::default().add_modifier(Modifier::REVERSED))
+            .style(Style::default().fg(Color::White));
+    }
+
+    // describes how long ago something happened, in the largest whole unit
+    fn describe_age(age: Duration) -> String
+    {
+        return match (age.num_minutes(), age.num_hours())
+        {
+            (0, _) => String::from("just now"),
+            (1, _) => String::from("1 minute ago"),
+            (minutes, 0) => format!("{} minutes ago", minutes),
+            (_, 1) => String::from("1 hour ago"),
+            (_, hours) => format!("{} hours ago", hours)
+        };
+    }
+
+    // returns a paragraph stating the controls, which days are in view when they don't all fit, and how fresh the forecast is
+    pub fn get_forecast_controls<'a>(forecast: &WeeklyForecast, visible: &Range<usize>, refresh_failed: bool) -> Paragraph<'a>
+    {
+        let mut updated: String = match forecast.fetched_at
+        {
+            Some(fetched_at) => format!("| updated {}", describe_age(Utc::now() - fetched_at)),
+            None => String::new()
+        };
+
+        if visible.len() < forecast.days.len()
+        {
+            updated.insert_str(0, &format!("| days {}-{} of {} ", visible.start + 1, visible.end, forecast.days.len()));
+        }
+
+        if refresh_failed
+        {
+            updated.push_str(" (refresh failed)");
+        }
+
+        let spans: Vec<Span> = vec!
+        [
+            Span::styled("Q", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+            Span::raw(": quit | "),
+            Span::styled("R", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+            Span::raw(": refresh | "),
+            Span::styled("C", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+            Span::raw(": chart | "),
+            Span::styled("M", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+            Span::raw(": menu | "),
+            Span::styled("H/L", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+            Span::raw(": day | "),
+            Span::styled("ENTER", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+            Span::raw(": details "),
+            Span::styled(updated, Style::default().fg(Color::DarkGray))
+        ];
+
+        return Paragraph::new(Spans::from(spans))
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::White)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // the home screen, with the days laid out to fit or the charts in their place
+    pub struct ForecastView;
+
+    impl ScreenView for ForecastView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            let days: usize = app.forecast.weekly.days.len();
+
+            // drawing simple elements
+            let main_panel: Vec<Rect> = get_forecast_panel(frame.size());
+            let layout: ForecastLayout = ForecastLayout::from_area(main_panel[2], days, app.settings.fields.len());
+            let visible: Range<usize> = match app.show_chart
+            {
+                true => 0..days,
+                false => layout.visible_range(app.day_index, days, main_panel[2])
+            };
+
+            frame.render_widget(get_forecast_title(&app.settings.location), main_panel[0]);
+            frame.render_widget(get_current_paragraph(&app.forecast.current, &app.forecast.hourly, &app.settings), main_panel[1]);
+            frame.render_widget(get_forecast_controls(&app.forecast.weekly, &visible, app.refresh_failed), main_panel[3]);
+
+            // drawing forecast, either as charts, a table of days or a column per day
+            if app.show_chart
+            {
+                chart_view::render_charts(&app.forecast, main_panel[2], frame);
+            }
+            else if layout == ForecastLayout::List
+            {
+                app.day_state.select(Some(app.day_index));
+
+                frame.render_stateful_widget(get_forecast_table(&app.forecast.weekly, &app.settings), main_panel[2], &mut app.day_state);
+            }
+            else
+            {
+                let count: usize = visible.len();
+                let first: usize = visible.start;
+                let forecast_panel: Vec<Rect> = get_forecast_slot_panel(main_panel[2], count);
+
+                for (slot, day) in app.forecast.weekly.days[visible].iter().enumerate()
+                {
+                    let slot_layout: Vec<Rect> = get_forecast_slot_layout(forecast_panel[slot], app.settings.fields.len());
+                    let paragraphs: Vec<Paragraph> = get_forecast_paragraphs(day, ForecastPosition::from_index(&slot, &count), slot_layout[2], first + slot == app.day_index, &app.forecast.weekly, &app.settings);
+
+                    for (paragraph, area) in paragraphs.into_iter().zip(slot_layout)
+                    {
+                        frame.render_widget(paragraph, area);
+                    }
+                }
+            }
+
+            // drawing the spinner over the stale forecast while a refresh is in flight
+            if app.fetching
+            {
+                let spinner_panel: Rect = loading_screen::get_spinner_panel(frame.size());
+
+                frame.render_widget(Clear, spinner_panel);
+                frame.render_widget(loading_screen::get_spinner(app.spinner_frame), spinner_panel);
+            }
+        }
+
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            let last_day: usize = app.forecast.weekly.days.len().saturating_sub(1);
+
+            match key.code
+            {
+                KeyCode::Char('q') => return Action::Quit,
+                KeyCode::Char('m') => app.screen = Screen::MENU,
+                KeyCode::Char('c') => app.show_chart = !app.show_chart,
+                KeyCode::Char('h') | KeyCode::Left => app.day_index = app.day_index.saturating_sub(1),
+                KeyCode::Char('l') | KeyCode::Right => app.day_index = (app.day_index + 1).min(last_day),
+                KeyCode::Enter if app.day_index < app.forecast.weekly.days.len() => app.screen = Screen::DAY,
+                KeyCode::Char('r') if !app.fetching =>
+                {
+                    app.fetching = true;
+                    return Action::Refresh;
+                },
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
+}
+
+pub mod hourly_screen
+{
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Paragraph, Table, Row, Cell},
+        layout::{Layout, Direction, Constraint, Rect, Alignment},
+        text::{Spans, Span}, style::{Style, Modifier, Color}
+    };
+    use chrono::NaiveDate;
+
+    use crate::weather::{HourlyWeather, HourlyForecast, display_measurement};
+    use crate::settings::Settings;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
+
+    // given the area to place it, returns the layout for the hourly screen
+    pub fn get_hourly_panel(area: Rect) -> Vec<Rect>
+    {
+        return Layout::default()
+            .direction(Direction::Vertical)
+            .constraints
+            ([
+                Constraint::Length(3),          // title
+                Constraint::Min(5),             // hours
+                Constraint::Length(3)           // controls
+            ])
+            .split(area);
+    }
+
+    // returns the title paragraph for the day being shown
+    pub fn get_hourly_title<'a>(date: NaiveDate, settings: &Settings) -> Paragraph<'a>
+    {
+        return Paragraph::new(format!("hourly for {}", settings.date_format.format(date)))
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::LightBlue)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // returns a table with a row per hour, the selected row is scrolled into view when rendered with its state
+    // unit suffixes come from the forecast the hours belong to so they always match the numbers
+    pub fn get_hourly_table<'a>(hours: &[HourlyWeather], forecast: &HourlyForecast, settings: &Settings) -> Table<'a>
+    {
+        let header: Row = Row::new(["time", "temp", "feels like", "precip", "wind", "conditions"])
+            .style(Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED))
+            .bottom_margin(1);
+
+        let rows: Vec<Row> = hours.iter()
+            .map
+            (
+                |hour| Row::new
+                (vec![
+                    Cell::from(settings.time_format.format(hour.hour())),
+                    Cell::from(display_measurement(&hour.temp, &forecast.unit("temperature_2m"))).style(Style::default().fg(Color::LightRed)),
+                    Cell::from(display_measurement(&hour.apparent_temp, &forecast.unit("apparent_temperature"))).style(Style::default().fg(Color::Cyan)),
+                    Cell::from(display_measurement(&hour.precipitation_probability, &forecast.unit("precipitation_probability"))).style(Style::default().fg(Color::Blue)),
+                    Cell::from(settings.wind.format(&hour.windspeed, &forecast.unit("windspeed_10m"), &hour.winddirection)),
+                    Cell::from(settings.icons.describe(&hour.condition)).style(Style::default().fg(Color::White))
+                ])
+            )
+            .collect();
+
+        return Table::new(rows)
+            .header(header)
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .widths
+            (&[
+                Constraint::Percentage(12),
+                Constraint::Percentage(13),
+                Constraint::Percentage(13),
+                Constraint::Percentage(12),
+                Constraint::Percentage(27),
+                Constraint::Percentage(23)
+            ])
+            .highlight_style
+            (
+                Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            )
+            .highlight_symbol("-> ");
+    }
+
+    // returns a paragraph stating the controls
+    pub fn get_hourly_controls<'a>() -> Paragraph<'a>
+    {
+        return Paragraph::new
+            (
+                Spans::from
+                (vec![
+                    Span::styled("Q", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": quit | "),
+                    Span::styled("J/K", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": scroll | "),
+                    Span::styled("M", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": menu ")
+                ])
+            )
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::White)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // today's forecast hour by hour
+    pub struct HourlyView;
+
+    impl ScreenView for HourlyView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            // today is in the location's timezone, not the one weatherman runs in
+            let today: NaiveDate = app.forecast.weekly.today();
+            let hours: Vec<HourlyWeather> = app.forecast.hourly.for_date(today);
+
+            let hourly_panel: Vec<Rect> = get_hourly_panel(frame.size());
+
+            frame.render_widget(get_hourly_title(today, &app.settings), hourly_panel[0]);
+            frame.render_stateful_widget(get_hourly_table(&hours, &app.forecast.hourly, &app.settings), hourly_panel[1], &mut app.hourly_state);
+            frame.render_widget(get_hourly_controls(), hourly_panel[2]);
+        }
+
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            match key.code
+            {
+                KeyCode::Char('q') => return Action::Quit,
+                KeyCode::Char('m') => app.screen = Screen::MENU,
+                KeyCode::Char('j') | KeyCode::Down =>
+                {
+                    let last: usize = app.forecast.hourly.for_date(app.forecast.weekly.today()).len().saturating_sub(1);
+                    app.hourly_state.select(Some(app.hourly_state.selected().map_or(0, |n| (n + 1).min(last))));
+                },
+                KeyCode::Char('k') | KeyCode::Up =>
+                {
+                    app.hourly_state.select(Some(app.hourly_state.selected().map_or(0, |n| n.saturating_sub(1))));
+                },
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
+}
+
+pub mod day_screen
+{
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Paragraph, Chart, Dataset, Axis, GraphType, Wrap},
+        layout::{Layout, Direction, Constraint, Rect, Alignment},
+        text::{Spans, Span}, style::{Style, Modifier, Color},
+        symbols
+    };
+    use chrono::{NaiveTime, Timelike};
+
+    use crate::weather::{DailyWeather, HourlyWeather, HourlyForecast, WeeklyForecast};
+    use crate::settings::{DailyField, Settings};
+    use super::chart_view::{Points, get_temperature_bounds};
+    use super::forecast_screen::{display_field, get_field_color};
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
+
+    // given the area to place it, returns the layout for the day detail screen
+    pub fn get_day_panel(area: Rect) -> Vec<Rect>
+    {
+        return Layout::default()
+            .direction(Direction::Vertical)
+            .constraints
+            ([
+                Constraint::Length(3),          // title
+                Constraint::Min(0),             // body
+                Constraint::Length(3)           // controls
+            ])
+            .split(area);
+    }
+
+    // splits the body into the list of details and the hourly chart beside it
+    pub fn get_day_body(area: Rect) -> Vec<Rect>
+    {
+        return Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints
+            ([
+                Constraint::Percentage(35),     // details
+                Constraint::Percentage(65)      // hourly chart
+            ])
+            .split(area);
+    }
+
+    // returns the title paragraph naming the day being shown
+    pub fn get_day_title<'a>(day: &DailyWeather, forecast: &WeeklyForecast, settings: &Settings) -> Paragraph<'a>
+    {
+        let today: &str = match forecast.is_today(day)
+        {
+            true => " (today)",
+            false => ""
+        };
+
+        return Paragraph::new(format!("{} {}{}", day.date.format("%A"), settings.date_format.format(day.date), today))
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::LightBlue)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // a label and its value as one line of the details
+    fn detail_line<'a>(label: &'a str, value: String, color: Color) -> Spans<'a>
+    {
+        return Spans::from
+        (vec![
+            Span::styled(format!("{:<21}", label), Style::default().fg(Color::DarkGray)),
+            Span::styled(value, Style::default().fg(color))
+        ]);
+    }
+
+    // returns every variable known about the day, formatted per the settings
+    pub fn get_day_details<'a>(day: &DailyWeather, forecast: &WeeklyForecast, settings: &Settings) -> Paragraph<'a>
+    {
+        let lines: Vec<Spans> = std::iter::once(detail_line("conditions", settings.icons.describe(&day.condition), Color::White))
+            .chain(DailyField::ALL.iter().map(|field| detail_line(field.label(), display_field(field, day, forecast, settings), get_field_color(field))))
+            .collect();
+
+        return Paragraph::new(lines)
+            .block
+            (
+                Block::default()
+                    .title("details")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .wrap(Wrap { trim: true });
+    }
+
+    // returns the temperature and feels like temperature of each hour, against the hour of the day
+    pub fn get_hourly_points(hours: &[HourlyWeather]) -> (Points, Points)
+    {
+        let temps: Points = hours.iter()
+            .filter_map(|hour| hour.temp.map(|temp| (hour.time.hour() as f64, temp as f64)))
+            .collect();
+
+        let feels: Points = hours.iter()
+            .filter_map(|hour| hour.apparent_temp.map(|temp| (hour.time.hour() as f64, temp as f64)))
+            .collect();
+
+        return (temps, feels);
+    }
+
+    // returns a line chart of the day's temperature through the hours
+    pub fn get_day_chart<'a>(temps: &'a [(f64, f64)], feels: &'a [(f64, f64)], hourly: &HourlyForecast, settings: &Settings) -> Chart<'a>
+    {
+        let unit: String = hourly.unit("temperature_2m");
+        let bounds: [f64; 2] = get_temperature_bounds(temps, feels);
+
+        let datasets: Vec<Dataset> = vec!
+        [
+            Dataset::default()
+                .name("temp")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::LightRed))
+                .data(temps),
+            Dataset::default()
+                .name("feels like")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Yellow))
+                .data(feels)
+        ];
+
+        let hour_labels: Vec<Span> = [0, 6, 12, 18, 23].iter()
+            .map(|hour| Span::styled(settings.time_format.format(NaiveTime::from_hms_opt(*hour, 0, 0).unwrap_or_default()), Style::default().fg(Color::Magenta)))
+            .collect();
+
+        let temp_labels: Vec<Span> = [bounds[0], (bounds[0] + bounds[1]) / 2.0, bounds[1]].iter()
+            .map(|temp| Span::raw(format!("{:.0}{}", temp, unit)))
+            .collect();
+
+        return Chart::new(datasets)
+            .block
+            (
+                Block::default()
+                    .title(Span::styled("hourly temperature", Style::default().add_modifier(Modifier::BOLD)))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .x_axis
+            (
+                Axis::default()
+                    .style(Style::default().fg(Color::DarkGray))
+                    .bounds([0.0, 23.0])
+                    .labels(hour_labels)
+            )
+            .y_axis
+            (
+                Axis::default()
+                    .style(Style::default().fg(Color::DarkGray))
+                    .bounds(bounds)
+                    .labels(temp_labels)
+            );
+    }
+
+    // returns a paragraph stating the controls for the day detail screen
+    pub fn get_day_controls<'a>() -> Paragraph<'a>
+    {
+        return Paragraph::new
+            (
+                Spans::from
+                (vec![
+                    Span::styled("Q", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": quit | "),
+                    Span::styled("H/L", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": day | "),
+                    Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": back | "),
+                    Span::styled("M", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": menu ")
+                ])
+            )
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::White)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // everything about the selected day
+    pub struct DayView;
+
+    impl ScreenView for DayView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            let day: &DailyWeather = match app.forecast.weekly.days.get(app.day_index)
+            {
+                Some(day) => day,
+                None => return
+            };
+
+            let hours: Vec<HourlyWeather> = app.forecast.hourly.for_date(day.date);
+
+            let day_panel: Vec<Rect> = get_day_panel(frame.size());
+            let day_body: Vec<Rect> = get_day_body(day_panel[1]);
+
+            let (temps, feels): (Points, Points) = get_hourly_points(&hours);
+
+            frame.render_widget(get_day_title(day, &app.forecast.weekly, &app.settings), day_panel[0]);
+            frame.render_widget(get_day_details(day, &app.forecast.weekly, &app.settings), day_body[0]);
+            frame.render_widget(get_day_chart(&temps, &feels, &app.forecast.hourly, &app.settings), day_body[1]);
+            frame.render_widget(get_day_controls(), day_panel[2]);
+        }
+
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            let last_day: usize = app.forecast.weekly.days.len().saturating_sub(1);
+
+            match key.code
+            {
+                KeyCode::Char('q') => return Action::Quit,
+                KeyCode::Char('m') => app.screen = Screen::MENU,
+                KeyCode::Esc => app.screen = Screen::FORECAST,
+                KeyCode::Char('h') | KeyCode::Left => app.day_index = app.day_index.saturating_sub(1),
+                KeyCode::Char('l') | KeyCode::Right => app.day_index = (app.day_index + 1).min(last_day),
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
+}
+
+pub mod chart_view
+{
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Chart, Dataset, Axis, GraphType, BarChart, Sparkline},
+        layout::{Layout, Direction, Constraint, Rect},
+        text::Span, style::{Style, Modifier, Color},
+        symbols
+    };
+
+    use crate::weather::{DailyWeather, Forecast, HourlyForecast, WeeklyForecast, display_measurement};
+
+    // draws the temperature chart, precipitation bars and probability sparklines over the body of the forecast screen
+    pub fn render_charts<B: Backend>(forecast: &Forecast, area: Rect, frame: &mut Frame<B>)
+    {
+        let chart_panel: Vec<Rect> = get_chart_panel(area);
+
+        let (highs, lows): (Points, Points) = get_temperature_points(&forecast.weekly);
+        frame.render_widget(get_temperature_chart(&highs, &lows, &forecast.weekly), chart_panel[0]);
+
+        let precipitation_bars: Vec<(String, u64)> = get_precipitation_bars(&forecast.weekly);
+        let precipitation_data: Vec<(&str, u64)> = precipitation_bars.iter().map(|(label, value)| (label.as_str(), *value)).collect();
+        frame.render_widget(get_precipitation_chart(&precipitation_data, chart_panel[1].width), chart_panel[1]);
+
+        let probability_series: Vec< Vec<u64> > = get_probability_series(&forecast.hourly, &forecast.weekly);
+        let sparkline_panel: Vec<Rect> = get_sparkline_panel(chart_panel[2], forecast.weekly.days.len());
+
+        for ((probabilities, day), area) in probability_series.iter().zip(forecast.weekly.days.iter()).zip(sparkline_panel)
+        {
+            frame.render_widget(get_probability_sparkline(probabilities, day), area);
+        }
+    }
+
+    // given the body of the forecast screen, returns the areas for the temperature chart, precipitation bars and probability sparklines
+    pub fn get_chart_panel(area: Rect) -> Vec<Rect>
+    {
+        return Layout::default()
+            .direction(Direction::Vertical)
+            .constraints
+            ([
+                Constraint::Percentage(50),     // temperature
+                Constraint::Percentage(28),     // precipitation
+                Constraint::Percentage(22)      // probability
+            ])
+            .split(area);
+    }
+
+    // splits an area into one equal column per day for the sparklines
+    pub fn get_sparkline_panel(area: Rect, days: usize) -> Vec<Rect>
+    {
+        return Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints(vec![Constraint::Ratio(1, days.max(1) as u32); days])
+            .split(area);
+    }
+
+    // (x, y) pairs a chart dataset is drawn from
+    pub type Points = Vec<(f64, f64)>;
+
+    // returns the (day index, temperature) points of the highs and the lows, leaving out days the api had no value for
+    pub fn get_temperature_points(forecast: &WeeklyForecast) -> (Points, Points)
+    {
+        let highs: Points = forecast.days.iter()
+            .enumerate()
+            .filter_map(|(i, day)| day.max_temp.map(|temp| (i as f64, temp as f64)))
+            .collect();
+
+        let lows: Points = forecast.days.iter()
+            .enumerate()
+            .filter_map(|(i, day)| day.min_temp.map(|temp| (i as f64, temp as f64)))
+            .collect();
+
+        return (highs, lows);
+    }
+
+    // returns the lowest and highest temperature across both sets of points, padded a little so the lines don't sit on the edges
+    pub fn get_temperature_bounds(highs: &[(f64, f64)], lows: &[(f64, f64)]) -> [f64; 2]
+    {
+        let temps = highs.iter().chain(lows.iter()).map(|point| point.1);
+
+        let min: f64 = temps.clone().fold(f64::INFINITY, f64::min);
+        let max: f64 = temps.fold(f64::NEG_INFINITY, f64::max);
+
+        if !min.is_finite() || !max.is_finite()
+        {
+            return [0.0, 1.0];
+        }
+
+        let padding: f64 = ((max - min) * 0.1).max(1.0);
+
+        return [(min - padding).floor(), (max + padding).ceil()];
+    }
+
+    // returns a line chart of the highs and lows against the days of the week
+    pub fn get_temperature_chart<'a>(highs: &'a [(f64, f64)], lows: &'a [(f64, f64)], forecast: &WeeklyForecast) -> Chart<'a>
+    {
+        let unit: String = forecast.unit("temperature_2m_max");
+        let bounds: [f64; 2] = get_temperature_bounds(highs, lows);
+
+        let datasets: Vec<Dataset> = vec!
+        [
+            Dataset::default()
+                .name("high")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::LightRed))
+                .data(highs),
+            Dataset::default()
+                .name("low")
+                .marker(symbols::Marker::Braille)
+                .graph_type(GraphType::Line)
+                .style(Style::default().fg(Color::Cyan))
+                .data(lows)
+        ];
+
+        let day_labels: Vec<Span> = forecast.days.iter()
+            .map(|day| Span::styled(day.date.format("%a").to_string(), Style::default().fg(Color::Magenta)))
+            .collect();
+
+        let temp_labels: Vec<Span> = [bounds[0], (bounds[0] + bounds[1]) / 2.0, bounds[1]].iter()
+            .map(|temp| Span::raw(format!("{:.0}{}", temp, unit)))
+            .collect();
+
+        return Chart::new(datasets)
+            .block
+            (
+                Block::default()
+                    .title(Span::styled("temperature", Style::default().add_modifier(Modifier::BOLD)))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
             )
-            .alignment(Alignment::Center);
+            .x_axis
+            (
+                Axis::default()
+                    .style(Style::default().fg(Color::DarkGray))
+                    .bounds([0.0, forecast.days.len().saturating_sub(1).max(1) as f64])
+                    .labels(day_labels)
+            )
+            .y_axis
+            (
+                Axis::default()
+                    .style(Style::default().fg(Color::DarkGray))
+                    .bounds(bounds)
+                    .labels(temp_labels)
+            );
+    }
+
+    // returns a bar per day labelled with its weekday and amount, bars are in hundredths so small amounts still show
+    pub fn get_precipitation_bars(forecast: &WeeklyForecast) -> Vec<(String, u64)>
+    {
+        let unit: String = forecast.unit("precipitation_sum");
+
+        return forecast.days.iter()
+            .map
+            (
+                |day| (
+                    format!("{} {}", day.date.format("%a"), display_measurement(&day.precipitation, &unit)),
+                    (day.precipitation.unwrap_or(0.0).max(0.0) * 100.0).round() as u64
+                )
+            )
+            .collect();
+    }
+
+    // returns a bar chart of each day's precipitation, sized so the bars fill the given width
+    pub fn get_precipitation_chart<'a>(bars: &'a [(&'a str, u64)], width: u16) -> BarChart<'a>
+    {
+        let bar_width: u16 = (width.saturating_sub(2) / bars.len().max(1) as u16).saturating_sub(1).max(1);
+
+        return BarChart::default()
+            .block
+            (
+                Block::default()
+                    .title(Span::styled("precipitation", Style::default().add_modifier(Modifier::BOLD)))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .data(bars)
+            .bar_width(bar_width)
+            .bar_gap(1)
+            .bar_style(Style::default().fg(Color::Blue))
+            // the raw hundredths are hidden in the bar, the real amount is in the label
+            .value_style(Style::default().fg(Color::Blue).bg(Color::Blue))
+            .label_style(Style::default().fg(Color::White));
+    }
+
+    // returns each day's hourly precipitation probabilities, with missing hours as 0
+    pub fn get_probability_series(hourly: &HourlyForecast, forecast: &WeeklyForecast) -> Vec< Vec<u64> >
+    {
+        return forecast.days.iter()
+            .map
+            (
+                |day| hourly.for_date(day.date).iter()
+                    .map(|hour| hour.precipitation_probability.unwrap_or(0.0).max(0.0).round() as u64)
+                    .collect()
+            )
+            .collect();
+    }
+
+    // returns a sparkline of a day's hourly precipitation probability, titled with the day and its peak
+    pub fn get_probability_sparkline<'a>(probabilities: &'a [u64], day: &DailyWeather) -> Sparkline<'a>
+    {
+        let peak: u64 = probabilities.iter().copied().max().unwrap_or(0);
+
+        return Sparkline::default()
+            .block
+            (
+                Block::default()
+                    .title(Span::styled(format!("{} {}%", day.date.format("%a"), peak), Style::default().fg(Color::Magenta)))
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Plain)
+            )
+            .data(probabilities)
+            .max(100)
+            .style(Style::default().fg(Color::LightBlue));
     }
 }
 
@@ -260,10 +1561,14 @@ pub mod menu_screen
 {
     use tui::
     {
-        widgets::{Block, Borders, BorderType, Paragraph, List, ListItem},
-        layout::{Layout, Direction, Constraint, Rect, Alignment},
-        text::{Spans, Span}, style::{Style, Modifier, Color}
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, List, ListItem},
+        layout::{Layout, Direction, Constraint, Rect},
+        style::{Style, Color}
     };
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
 
     pub fn get_menu_panel(area: Rect) -> Vec<Rect>
     {
@@ -288,11 +1593,188 @@ pub mod menu_screen
             .split(cols[1]);
     }
 
+    // how many entries the menu list has, for wrapping the selection around
+    pub const MENU_LENGTH: usize = 4;
+
     pub fn get_menu_list<'a>() -> List<'a>
     {
-        let items: [ListItem; 3] = [ ListItem::new("weekly forecast"), ListItem::new("hourly for today"), ListItem::new("options")];
+        let items: [ListItem; MENU_LENGTH] = [ ListItem::new("weekly forecast"), ListItem::new("hourly for today"), ListItem::new("change location"), ListItem::new("options")];
+
+        return List::new(items)
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+            )
+            .highlight_style
+            (
+                Style::default()
+                    .fg(Color::Cyan)
+            )
+            .highlight_symbol("-> ");
+    }
+
+    // the list of every other screen
+    pub struct MenuView;
+
+    impl ScreenView for MenuView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            let menu_panel: Vec<Rect> = get_menu_panel(frame.size());
+
+            frame.render_stateful_widget(get_menu_list(), menu_panel[1], &mut app.menu_state);
+        }
+
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            match key.code
+            {
+                KeyCode::Char('q') => return Action::Quit,
+                KeyCode::Char('j') =>
+                {
+                    app.menu_state.select(Some(app.menu_state.selected().map_or(0, |n| (n + 1) % MENU_LENGTH)));
+                },
+                KeyCode::Char('k') =>
+                {
+                    app.menu_state.select(Some(app.menu_state.selected().map_or(MENU_LENGTH - 1, |n| (n + MENU_LENGTH - 1) % MENU_LENGTH)));
+                },
+                KeyCode::Enter =>
+                {
+                    if let Some(sel_index) = app.menu_state.selected()
+                    {
+                        app.screen = match sel_index
+                        {
+                            0 => Screen::FORECAST,
+                            1 => Screen::HOURLY,
+                            2 => Screen::SEARCH,
+                            3 => Screen::OPTIONS,
+                            _ => Screen::FORECAST
+                        };
+                    }
+                },
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
+}
+
+pub mod search_screen
+{
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Paragraph, List, ListItem},
+        layout::{Layout, Direction, Constraint, Rect, Alignment},
+        text::{Spans, Span}, style::{Style, Modifier, Color}
+    };
+
+    use crate::weather::{GeocodingResult, Location};
+    use crate::settings::SettingField;
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
+
+    // returns the layout for the search screen: input, results and controls
+    pub fn get_search_panel(area: Rect) -> Vec<Rect>
+    {
+        let cols: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints
+            ([
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20)
+            ])
+            .split(area);
+
+        return Layout::default()
+            .direction(Direction::Vertical)
+            .constraints
+            ([
+                Constraint::Percentage(10),
+                Constraint::Length(3),          // input
+                Constraint::Min(5),             // results
+                Constraint::Length(3),          // controls
+                Constraint::Percentage(10)
+            ])
+            .split(cols[1]);
+    }
+
+    // returns the text input showing what has been typed so far
+    pub fn get_search_input<'a>(query: &str) -> Paragraph<'a>
+    {
+        return Paragraph::new
+            (
+                Spans::from
+                (vec![
+                    Span::raw(query.to_string()),
+                    Span::styled("_", Style::default().add_modifier(Modifier::SLOW_BLINK))
+                ])
+            )
+            .block
+            (
+                Block::default()
+                    .title("place name or postal code")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::White)
+            );
+    }
+
+    // returns the list of candidate locations, with the status of the last search as its title
+    pub fn get_search_results<'a>(results: &[GeocodingResult], status: &str) -> List<'a>
+    {
+        let items: Vec<ListItem> = results.iter()
+            .map(|result| ListItem::new(result.to_string()))
+            .collect();
 
         return List::new(items)
+            .block
+            (
+                Block::default()
+                    .title(status.to_string())
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+            )
+            .highlight_style
+            (
+                Style::default()
+                    .fg(Color::Cyan)
+            )
+            .highlight_symbol("-> ");
+    }
+
+    // returns a paragraph stating the controls
+    pub fn get_search_controls<'a>() -> Paragraph<'a>
+    {
+        return Paragraph::new
+            (
+                Spans::from
+                (vec![
+                    Span::styled("ENTER", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": search / pick | "),
+                    Span::styled("UP/DOWN", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": select | "),
+                    Span::styled("ESC", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": menu ")
+                ])
+            )
             .block
             (
                 Block::default()
@@ -302,12 +1784,248 @@ pub mod menu_screen
             .style
             (
                 Style::default()
+                    .fg(Color::White)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // picking a new location by name
+    pub struct SearchView;
+
+    impl ScreenView for SearchView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            let search_panel: Vec<Rect> = get_search_panel(frame.size());
+
+            frame.render_widget(get_search_input(&app.search_query), search_panel[1]);
+            frame.render_stateful_widget(get_search_results(&app.search_results, &app.search_status), search_panel[2], &mut app.search_state);
+            frame.render_widget(get_search_controls(), search_panel[3]);
+        }
+
+        // typing goes into the query, so only escape leaves this screen
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            let results: usize = app.search_results.len();
+
+            match key.code
+            {
+                KeyCode::Esc => app.screen = Screen::MENU,
+                KeyCode::Char(c) =>
+                {
+                    app.search_query.push(c);
+                    app.search_dirty = true;
+                },
+                KeyCode::Backspace =>
+                {
+                    app.search_query.pop();
+                    app.search_dirty = true;
+                },
+                KeyCode::Down if results > 0 =>
+                {
+                    app.search_state.select(Some(app.search_state.selected().map_or(0, |n| (n + 1) % results)));
+                },
+                KeyCode::Up if results > 0 =>
+                {
+                    app.search_state.select(Some(app.search_state.selected().map_or(0, |n| (n + results - 1) % results)));
+                },
+                KeyCode::Enter if app.search_dirty =>
+                {
+                    app.search_status = format!("searching for \"{}\"...", app.search_query.trim());
+                    return Action::Search;
+                },
+                KeyCode::Enter =>
+                {
+                    if let Some(location) = app.search_state.selected().and_then(|n| app.search_results.get(n)).map(Location::from)
+                    {
+                        app.settings.location = location;
+                        app.save_settings(SettingField::Location);
+                        app.hourly_state.select(Some(0));
+
+                        return Action::Fetch;
+                    }
+                },
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
+}
+
+pub mod options_screen
+{
+    use tui::
+    {
+        backend::Backend, Frame,
+        widgets::{Block, Borders, BorderType, Paragraph, List, ListItem},
+        layout::{Layout, Direction, Constraint, Rect, Alignment},
+        text::{Spans, Span}, style::{Style, Modifier, Color}
+    };
+
+    use crate::settings::{Settings, SettingField};
+    use crossterm::event::{KeyCode, KeyEvent};
+    use crate::app::{App, Action};
+    use super::{Screen, ScreenView};
+
+    // returns the layout for the options screen: settings list, status line and controls
+    pub fn get_options_panel(area: Rect) -> Vec<Rect>
+    {
+        let cols: Vec<Rect> = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints
+            ([
+                Constraint::Percentage(20),
+                Constraint::Percentage(60),
+                Constraint::Percentage(20)
+            ])
+            .split(area);
+
+        return Layout::default()
+            .direction(Direction::Vertical)
+            .constraints
+            ([
+                Constraint::Percentage(15),
+                Constraint::Length(SettingField::ALL.len() as u16 + 2),   // settings
+                Constraint::Length(1),                                    // status
+                Constraint::Length(3),                                    // controls
+                Constraint::Min(0)
+            ])
+            .split(cols[1]);
+    }
+
+    // returns the list of every setting alongside its current value
+    pub fn get_options_list<'a>(settings: &Settings) -> List<'a>
+    {
+        let items: Vec<ListItem> = SettingField::ALL.iter()
+            .map
+            (
+                |field| ListItem::new
+                (
+                    Spans::from
+                    (vec![
+                        Span::styled(format!("{:<20}", field.label()), Style::default().fg(Color::Magenta)),
+                        Span::raw(settings.value(*field))
+                    ])
+                )
+            )
+            .collect();
+
+        return List::new(items)
+            .block
+            (
+                Block::default()
+                    .title("options")
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
             )
             .highlight_style
             (
                 Style::default()
                     .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
             )
             .highlight_symbol("-> ");
     }
+
+    // returns a single line describing the result of the last change
+    pub fn get_options_status<'a>(status: &str) -> Paragraph<'a>
+    {
+        return Paragraph::new(status.to_string())
+            .style
+            (
+                Style::default()
+                    .fg(Color::DarkGray)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // returns a paragraph stating the controls
+    pub fn get_options_controls<'a>() -> Paragraph<'a>
+    {
+        return Paragraph::new
+            (
+                Spans::from
+                (vec![
+                    Span::styled("J/K", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": select | "),
+                    Span::styled("H/L", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": change | "),
+                    Span::styled("ENTER", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": edit | "),
+                    Span::styled("M", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": menu | "),
+                    Span::styled("Q", Style::default().add_modifier(Modifier::BOLD).add_modifier(Modifier::UNDERLINED)),
+                    Span::raw(": quit ")
+                ])
+            )
+            .block
+            (
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_type(BorderType::Rounded)
+            )
+            .style
+            (
+                Style::default()
+                    .fg(Color::White)
+            )
+            .alignment(Alignment::Center);
+    }
+
+    // editing the settings in place
+    pub struct OptionsView;
+
+    impl ScreenView for OptionsView
+    {
+        fn render<B: Backend>(&self, app: &mut App, frame: &mut Frame<B>)
+        {
+            let options_panel: Vec<Rect> = get_options_panel(frame.size());
+
+            frame.render_stateful_widget(get_options_list(&app.settings), options_panel[1], &mut app.options_state);
+            frame.render_widget(get_options_status(&app.options_status), options_panel[2]);
+            frame.render_widget(get_options_controls(), options_panel[3]);
+        }
+
+        fn handle_key(&self, app: &mut App, key: KeyEvent) -> Action
+        {
+            let field: SettingField = SettingField::ALL[app.options_state.selected().unwrap_or(0)];
+
+            match key.code
+            {
+                KeyCode::Char('q') => return Action::Quit,
+                KeyCode::Char('m') | KeyCode::Esc => app.screen = Screen::MENU,
+                KeyCode::Char('j') | KeyCode::Down =>
+                {
+                    app.options_state.select(Some(app.options_state.selected().map_or(0, |n| (n + 1) % SettingField::ALL.len())));
+                },
+                KeyCode::Char('k') | KeyCode::Up =>
+                {
+                    app.options_state.select(Some(app.options_state.selected().map_or(0, |n| (n + SettingField::ALL.len() - 1) % SettingField::ALL.len())));
+                },
+                // the location is edited on its own screen
+                KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right | KeyCode::Char('h') | KeyCode::Left if field == SettingField::Location => app.screen = Screen::SEARCH,
+                KeyCode::Enter | KeyCode::Char('l') | KeyCode::Right | KeyCode::Char('h') | KeyCode::Left =>
+                {
+                    let forward: bool = !matches!(key.code, KeyCode::Char('h') | KeyCode::Left);
+
+                    // nothing to save or fetch when there is only the one value to step to
+                    if !app.settings.cycle(field, forward)
+                    {
+                        return Action::None;
+                    }
+
+                    app.save_settings(field);
+
+                    if field.changes_query()
+                    {
+                        return Action::Fetch;
+                    }
+                },
+                _ => {}
+            }
+
+            return Action::None;
+        }
+    }
 }
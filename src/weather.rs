@@ -1,18 +1,554 @@
-pub use std::collections::HashMap;
-pub use serde::{Serialize, Deserialize};
-pub use std::fmt::{self, Display, Formatter};
+use std::collections::HashMap;
+use serde::{Serialize, Deserialize};
+use std::fmt::{self, Display, Formatter};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+// the place a forecast is requested for
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Location
+{
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: Option<f32>,
+    pub timezone: String
+}
+
+impl Location
+{
+    pub fn new(name: &str, latitude: f64, longitude: f64, elevation: Option<f32>, timezone: &str) -> Location
+    {
+        return Location
+        {
+            name: name.to_string(),
+            latitude,
+            longitude,
+            elevation,
+            timezone: timezone.to_string()
+        };
+    }
+}
+
+// the location weatherman has always shown
+impl Default for Location
+{
+    fn default() -> Location
+    {
+        return Location::new("St. Clair Shores, MI", 42.64, -82.96, None, "America/New_York");
+    }
+}
+
+impl Display for Location
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        return fmt.write_fmt(format_args!("{} ({:.2}, {:.2})", self.name, self.latitude, self.longitude));
+    }
+}
+
+// unit the api reports temperatures in
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum TemperatureUnit
+{
+    Celsius,
+    Fahrenheit
+}
+
+impl TemperatureUnit
+{
+    pub const ALL: [TemperatureUnit; 2] = [TemperatureUnit::Celsius, TemperatureUnit::Fahrenheit];
+
+    // value of the api's temperature_unit parameter
+    pub fn query_value(&self) -> &'static str
+    {
+        return match self
+        {
+            TemperatureUnit::Celsius => "celsius",
+            TemperatureUnit::Fahrenheit => "fahrenheit"
+        };
+    }
+
+    pub fn suffix(&self) -> &'static str
+    {
+        return match self
+        {
+            TemperatureUnit::Celsius => "°C",
+            TemperatureUnit::Fahrenheit => "°F"
+        };
+    }
+}
+
+// unit the api reports wind speeds in
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum WindUnit
+{
+    KilometersPerHour,
+    MetersPerSecond,
+    MilesPerHour,
+    Knots
+}
+
+impl WindUnit
+{
+    pub const ALL: [WindUnit; 4] = [WindUnit::KilometersPerHour, WindUnit::MetersPerSecond, WindUnit::MilesPerHour, WindUnit::Knots];
+
+    // value of the api's windspeed_unit parameter
+    pub fn query_value(&self) -> &'static str
+    {
+        return match self
+        {
+            WindUnit::KilometersPerHour => "kmh",
+            WindUnit::MetersPerSecond => "ms",
+            WindUnit::MilesPerHour => "mph",
+            WindUnit::Knots => "kn"
+        };
+    }
+
+    pub fn suffix(&self) -> &'static str
+    {
+        return match self
+        {
+            WindUnit::KilometersPerHour => "km/h",
+            WindUnit::MetersPerSecond => "m/s",
+            WindUnit::MilesPerHour => "mph",
+            WindUnit::Knots => "kn"
+        };
+    }
+
+    // the unit a response's tidied up wind unit stands for
+    pub fn from_suffix(suffix: &str) -> Option<WindUnit>
+    {
+        return WindUnit::ALL.iter().find(|unit| unit.suffix() == suffix).copied();
+    }
+
+    pub fn to_meters_per_second(&self, speed: f32) -> f32
+    {
+        return match self
+        {
+            WindUnit::KilometersPerHour => speed / 3.6,
+            WindUnit::MetersPerSecond => speed,
+            WindUnit::MilesPerHour => speed * 0.44704,
+            WindUnit::Knots => speed * 0.514444
+        };
+    }
+}
+
+// the 16 points of the compass, clockwise from north
+pub const COMPASS_POINTS: [&str; 16] = ["N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE", "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW"];
+
+// arrows pointing the way the wind blows, which is away from the direction it is reported as coming from
+pub const WIND_ARROWS: [&str; 8] = ["↓", "↙", "←", "↖", "↑", "↗", "→", "↘"];
+
+// the compass point nearest to a direction in degrees
+pub fn compass_point(degrees: f32) -> &'static str
+{
+    return COMPASS_POINTS[(degrees.rem_euclid(360.0) / 22.5).round() as usize % COMPASS_POINTS.len()];
+}
+
+// the arrow for wind coming from a direction in degrees
+pub fn wind_arrow(degrees: f32) -> &'static str
+{
+    return WIND_ARROWS[(degrees.rem_euclid(360.0) / 45.0).round() as usize % WIND_ARROWS.len()];
+}
+
+// upper bounds in meters per second of each beaufort force below 12
+const BEAUFORT_LIMITS: [f32; 12] = [0.5, 1.6, 3.4, 5.5, 8.0, 10.8, 13.9, 17.2, 20.8, 24.5, 28.5, 32.7];
+
+// the beaufort force of a windspeed in meters per second
+pub fn beaufort_force(meters_per_second: f32) -> u8
+{
+    return BEAUFORT_LIMITS.iter().position(|limit| meters_per_second < *limit).unwrap_or(BEAUFORT_LIMITS.len()) as u8;
+}
+
+pub fn beaufort_description(force: u8) -> &'static str
+{
+    return match force
+    {
+        0 => "calm",
+        1 => "light air",
+        2 => "light breeze",
+        3 => "gentle breeze",
+        4 => "moderate breeze",
+        5 => "fresh breeze",
+        6 => "strong breeze",
+        7 => "near gale",
+        8 => "gale",
+        9 => "strong gale",
+        10 => "storm",
+        11 => "violent storm",
+        _ => "hurricane force"
+    };
+}
+
+// unit the api reports precipitation amounts in
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum PrecipitationUnit
+{
+    Millimeters,
+    Inches
+}
+
+impl PrecipitationUnit
+{
+    pub const ALL: [PrecipitationUnit; 2] = [PrecipitationUnit::Millimeters, PrecipitationUnit::Inches];
+
+    // value of the api's precipitation_unit parameter
+    pub fn query_value(&self) -> &'static str
+    {
+        return match self
+        {
+            PrecipitationUnit::Millimeters => "mm",
+            PrecipitationUnit::Inches => "inch"
+        };
+    }
+
+    pub fn suffix(&self) -> &'static str
+    {
+        return match self
+        {
+            PrecipitationUnit::Millimeters => "mm",
+            PrecipitationUnit::Inches => "in"
+        };
+    }
+}
+
+// every unit the forecast is requested in
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Units
+{
+    pub temperature: TemperatureUnit,
+    pub wind: WindUnit,
+    pub precipitation: PrecipitationUnit
+}
+
+// named sets of units that can be switched between in one go
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum UnitSystem
+{
+    Metric,
+    Imperial,
+    Mixed,
+    Custom
+}
+
+impl UnitSystem
+{
+    // the presets that can be picked, custom is whatever doesn't match one of them
+    pub const PRESETS: [UnitSystem; 3] = [UnitSystem::Metric, UnitSystem::Imperial, UnitSystem::Mixed];
+
+    pub fn units(&self) -> Option<Units>
+    {
+        return match self
+        {
+            UnitSystem::Metric => Some(Units { temperature: TemperatureUnit::Celsius, wind: WindUnit::KilometersPerHour, precipitation: PrecipitationUnit::Millimeters }),
+            UnitSystem::Imperial => Some(Units { temperature: TemperatureUnit::Fahrenheit, wind: WindUnit::MilesPerHour, precipitation: PrecipitationUnit::Inches }),
+            // celsius and millimeters with miles per hour, as used in the uk
+            UnitSystem::Mixed => Some(Units { temperature: TemperatureUnit::Celsius, wind: WindUnit::MilesPerHour, precipitation: PrecipitationUnit::Millimeters }),
+            UnitSystem::Custom => None
+        };
+    }
+}
+
+impl Display for UnitSystem
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        return fmt.write_str
+        (
+            match self
+            {
+                UnitSystem::Metric => "metric",
+                UnitSystem::Imperial => "imperial",
+                UnitSystem::Mixed => "mixed",
+                UnitSystem::Custom => "custom"
+            }
+        );
+    }
+}
+
+impl Units
+{
+    // which preset these units are, if any
+    pub fn system(&self) -> UnitSystem
+    {
+        return UnitSystem::PRESETS.into_iter()
+            .find(|system| system.units().as_ref() == Some(self))
+            .unwrap_or(UnitSystem::Custom);
+    }
+}
+
+// the units weatherman has always shown
+impl Default for Units
+{
+    fn default() -> Units
+    {
+        return Units
+        {
+            temperature: TemperatureUnit::Fahrenheit,
+            wind: WindUnit::MilesPerHour,
+            precipitation: PrecipitationUnit::Inches
+        };
+    }
+}
+
+// how many days the forecast covers from today, and how many already passed days come before them
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Horizon
+{
+    pub forecast_days: u8,
+    pub past_days: u8
+}
+
+impl Horizon
+{
+    // the api gives at most 16 days ahead, and more than a week back isn't worth the room
+    pub const FORECAST_DAYS: [u8; 6] = [3, 5, 7, 10, 14, 16];
+    pub const PAST_DAYS: [u8; 5] = [0, 1, 2, 3, 7];
+}
+
+impl Default for Horizon
+{
+    fn default() -> Horizon
+    {
+        return Horizon
+        {
+            forecast_days: 7,
+            past_days: 0
+        };
+    }
+}
+
+// a single candidate place in the geocoding api's response
+#[derive(Deserialize, Clone, Debug)]
+pub struct GeocodingResult
+{
+    pub id: u64,
+    pub name: String,
+    pub latitude: f64,
+    pub longitude: f64,
+    pub elevation: Option<f32>,
+    pub timezone: Option<String>,
+    pub country: Option<String>,
+    pub country_code: Option<String>,
+    pub admin1: Option<String>,
+    pub admin2: Option<String>,
+    pub population: Option<u64>,
+    #[serde(default)]
+    pub postcodes: Vec<String>
+}
+
+// format of the geocoding api's json response, 'results' is left out entirely when nothing matched
+#[allow(dead_code)]
+#[derive(Deserialize, Debug)]
+pub struct GeocodingResponse
+{
+    #[serde(default)]
+    results: Vec<GeocodingResult>,
+    generationtime_ms: Option<f64>
+}
+
+// human readable name with the region and country for telling candidates apart
+impl Display for GeocodingResult
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        let mut parts: Vec<&str> = vec![self.name.as_str()];
+
+        for region in [&self.admin1, &self.country].into_iter().flatten()
+        {
+            if region != &self.name
+            {
+                parts.push(region.as_str());
+            }
+        }
+
+        return fmt.write_str(&parts.join(", "));
+    }
+}
+
+impl From<&GeocodingResult> for Location
+{
+    fn from(result: &GeocodingResult) -> Location
+    {
+        return Location
+        {
+            name: match &result.admin1
+            {
+                Some(admin1) if admin1 != &result.name => format!("{}, {}", result.name, admin1),
+                _ => result.name.clone()
+            },
+            latitude: result.latitude,
+            longitude: result.longitude,
+            elevation: result.elevation,
+            timezone: result.timezone.clone().unwrap_or("auto".to_string())
+        };
+    }
+}
+
+// broad kinds of weather the api's wmo weather codes fall into
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum WeatherCondition
+{
+    Clear,
+    PartlyCloudy,
+    Cloudy,
+    Fog,
+    Drizzle,
+    Rain,
+    FreezingRain,
+    Snow,
+    Showers,
+    Thunderstorm,
+    Hail
+}
+
+impl WeatherCondition
+{
+    // maps a wmo weather code, as documented by open-meteo, to its condition
+    pub fn from_code(code: u8) -> Option<WeatherCondition>
+    {
+        return match code
+        {
+            0 | 1 => Some(WeatherCondition::Clear),
+            2 => Some(WeatherCondition::PartlyCloudy),
+            3 => Some(WeatherCondition::Cloudy),
+            45 | 48 => Some(WeatherCondition::Fog),
+            51 | 53 | 55 => Some(WeatherCondition::Drizzle),
+            61 | 63 | 65 => Some(WeatherCondition::Rain),
+            56 | 57 | 66 | 67 => Some(WeatherCondition::FreezingRain),
+            71 | 73 | 75 | 77 | 85 | 86 => Some(WeatherCondition::Snow),
+            80..=82 => Some(WeatherCondition::Showers),
+            95 => Some(WeatherCondition::Thunderstorm),
+            96 | 99 => Some(WeatherCondition::Hail),
+            _ => None
+        };
+    }
+
+    pub fn emoji(&self) -> &'static str
+    {
+        return match self
+        {
+            WeatherCondition::Clear => "☀️",
+            WeatherCondition::PartlyCloudy => "⛅",
+            WeatherCondition::Cloudy => "☁️",
+            WeatherCondition::Fog => "🌫️",
+            WeatherCondition::Drizzle => "🌦️",
+            WeatherCondition::Rain => "🌧️",
+            WeatherCondition::FreezingRain => "🧊",
+            WeatherCondition::Snow => "❄️",
+            WeatherCondition::Showers => "🌦️",
+            WeatherCondition::Thunderstorm => "⛈️",
+            WeatherCondition::Hail => "🌨️"
+        };
+    }
+
+    // for terminals and fonts that can't show emoji
+    pub fn ascii(&self) -> &'static str
+    {
+        return match self
+        {
+            WeatherCondition::Clear => "(O)",
+            WeatherCondition::PartlyCloudy => "(O~",
+            WeatherCondition::Cloudy => "~~~",
+            WeatherCondition::Fog => "===",
+            WeatherCondition::Drizzle => "','",
+            WeatherCondition::Rain => "///",
+            WeatherCondition::FreezingRain => "/*/",
+            WeatherCondition::Snow => "***",
+            WeatherCondition::Showers => "~//",
+            WeatherCondition::Thunderstorm => "/z/",
+            WeatherCondition::Hail => "o.o"
+        };
+    }
+}
+
+impl Display for WeatherCondition
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        return fmt.write_str
+        (
+            match self
+            {
+                WeatherCondition::Clear => "clear",
+                WeatherCondition::PartlyCloudy => "partly cloudy",
+                WeatherCondition::Cloudy => "cloudy",
+                WeatherCondition::Fog => "fog",
+                WeatherCondition::Drizzle => "drizzle",
+                WeatherCondition::Rain => "rain",
+                WeatherCondition::FreezingRain => "freezing rain",
+                WeatherCondition::Snow => "snow",
+                WeatherCondition::Showers => "showers",
+                WeatherCondition::Thunderstorm => "thunderstorm",
+                WeatherCondition::Hail => "hail"
+            }
+        );
+    }
+}
 
 // 'daily' object in APIResponse
 #[derive(Deserialize, Debug)]
 pub struct APIDaily
 {
     time: Vec<String>,
-    temperature_2m_max: Vec<f32>,
-    temperature_2m_min: Vec<f32>,
-    sunrise: Vec<String>,
-    sunset: Vec<String>,
-    precipitation_sum: Vec<f32>,
-    windspeed_10m_max: Vec<f32>
+    temperature_2m_max: Vec<Option<f32>>,
+    temperature_2m_min: Vec<Option<f32>>,
+    sunrise: Vec<Option<String>>,
+    sunset: Vec<Option<String>>,
+    precipitation_sum: Vec<Option<f32>>,
+    windspeed_10m_max: Vec<Option<f32>>,
+    weathercode: Vec<Option<u8>>,
+    // not every field is always shown, so a response without them still loads
+    #[serde(default)]
+    apparent_temperature_max: Vec<Option<f32>>,
+    #[serde(default)]
+    apparent_temperature_min: Vec<Option<f32>>,
+    #[serde(default)]
+    uv_index_max: Vec<Option<f32>>,
+    #[serde(default)]
+    precipitation_hours: Vec<Option<f32>>,
+    #[serde(default)]
+    windgusts_10m_max: Vec<Option<f32>>,
+    #[serde(default)]
+    winddirection_10m_dominant: Vec<Option<f32>>,
+    #[serde(default)]
+    sunshine_duration: Vec<Option<f32>>,
+    #[serde(default)]
+    precipitation_probability_max: Vec<Option<f32>>,
+    #[serde(default)]
+    rain_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    showers_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    snowfall_sum: Vec<Option<f32>>,
+    #[serde(default)]
+    shortwave_radiation_sum: Vec<Option<f32>>
+}
+
+// 'hourly' object in APIResponse
+#[derive(Deserialize, Debug)]
+pub struct APIHourly
+{
+    time: Vec<String>,
+    temperature_2m: Vec<Option<f32>>,
+    apparent_temperature: Vec<Option<f32>>,
+    precipitation_probability: Vec<Option<f32>>,
+    windspeed_10m: Vec<Option<f32>>,
+    weathercode: Vec<Option<u8>>,
+    #[serde(default)]
+    winddirection_10m: Vec<Option<f32>>
+}
+
+// 'current_weather' object in APIResponse
+#[derive(Deserialize, Debug)]
+pub struct APICurrentWeather
+{
+    time: String,
+    temperature: f32,
+    windspeed: f32,
+    winddirection: f32,
+    weathercode: u8,
+    is_day: u8
 }
 
 // format of the weather api's json response
@@ -28,20 +564,90 @@ pub struct APIResponse
     timezone_abbreviation: String,
     elevation: f32,
     daily_units: HashMap<String, String>,
-    daily: APIDaily
+    daily: APIDaily,
+    hourly_units: HashMap<String, String>,
+    hourly: APIHourly,
+    current_weather: Option<APICurrentWeather>
 }
 
 // necessary information for each day's weather
 #[derive(Clone, Debug)]
+// anything the api can send back as null is optional, like the sunrise during a polar day
+// times are in the location's own utc offset
 pub struct DailyWeather
 {
-    pub date: String,
-    pub max_temp: f32,
-    pub min_temp: f32,
-    pub sunrise: String,
-    pub sunset: String,
-    pub precipitation: f32,
-    pub max_windspeed: f32
+    pub date: NaiveDate,
+    pub max_temp: Option<f32>,
+    pub min_temp: Option<f32>,
+    pub sunrise: Option<DateTime<FixedOffset>>,
+    pub sunset: Option<DateTime<FixedOffset>>,
+    pub precipitation: Option<f32>,
+    pub max_windspeed: Option<f32>,
+    pub condition: Option<WeatherCondition>,
+    pub apparent_max_temp: Option<f32>,
+    pub apparent_min_temp: Option<f32>,
+    pub uv_index: Option<f32>,
+    pub precipitation_hours: Option<f32>,
+    pub max_windgusts: Option<f32>,
+    // degrees the wind mostly blows from
+    pub wind_direction: Option<f32>,
+    pub sunshine: Option<Duration>,
+    // percent chance at the most likely hour
+    pub precipitation_probability: Option<f32>,
+    pub rain: Option<f32>,
+    pub showers: Option<f32>,
+    pub snowfall: Option<f32>,
+    // total sunlight energy over the day
+    pub radiation: Option<f32>
+}
+
+impl DailyWeather
+{
+    // how long the sun is up, unknown when it never rises or never sets
+    pub fn daylight(&self) -> Option<Duration>
+    {
+        return match (self.sunrise, self.sunset)
+        {
+            (Some(sunrise), Some(sunset)) => Some(sunset - sunrise),
+            _ => None
+        };
+    }
+}
+
+// shows a duration as hours and minutes, like "9h 48m"
+pub fn display_duration(duration: &Duration) -> String
+{
+    return format!("{}h {:02}m", duration.num_hours(), duration.num_minutes() % 60);
+}
+
+// shows a missing value as a placeholder instead of leaving a gap
+pub fn display_option<T: Display>(value: &Option<T>) -> String
+{
+    return match value
+    {
+        Some(value) => value.to_string(),
+        None => String::from("--")
+    };
+}
+
+// shows a value with its unit, or the placeholder on its own when it is missing
+pub fn display_measurement(value: &Option<f32>, unit: &str) -> String
+{
+    return match value
+    {
+        Some(value) => format!("{}{}", value, unit),
+        None => String::from("--")
+    };
+}
+
+// shows the direction wind comes from as an arrow and compass point, like "↙ NE"
+pub fn display_direction(degrees: &Option<f32>) -> String
+{
+    return match degrees
+    {
+        Some(degrees) => format!("{} {}", wind_arrow(*degrees), compass_point(*degrees)),
+        None => String::from("--")
+    };
 }
 
 // to be able to conver to string and cleanly output
@@ -49,21 +655,32 @@ impl Display for DailyWeather
 {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
     {
-        let date_split: Vec<&str> = self.date.split("-")
-            .collect();
-
         return fmt.write_fmt
         (
             format_args!
             (
-                "date: {}\nmax temp: {}\nmin temp: {}\nsunrise: {}\nsunset: {}\nprecipitation: {}\nmax windspeed: {}",
-                format!("{}/{}/{}", date_split[1], date_split[2], date_split[0]),
-                self.max_temp,
-                self.min_temp,
-                self.sunrise,
-                self.sunset,
-                self.precipitation,
-                self.max_windspeed
+                "date: {}\ncondition: {}\nmax temp: {}\nmin temp: {}\nfeels like max: {}\nfeels like min: {}\nsunrise: {}\nsunset: {}\ndaylight: {}\nsunshine: {}\nuv index: {}\nprecipitation: {}\nprecipitation hours: {}\nprecipitation probability: {}\nrain: {}\nshowers: {}\nsnowfall: {}\nmax windspeed: {}\nmax gusts: {}\nwind direction: {}\nradiation: {}",
+                self.date.format("%a %m/%d/%Y"),
+                display_option(&self.condition),
+                display_option(&self.max_temp),
+                display_option(&self.min_temp),
+                display_option(&self.apparent_max_temp),
+                display_option(&self.apparent_min_temp),
+                display_option(&self.sunrise.map(|n| n.format("%H:%M"))),
+                display_option(&self.sunset.map(|n| n.format("%H:%M"))),
+                display_option(&self.daylight().map(|n| display_duration(&n))),
+                display_option(&self.sunshine.map(|n| display_duration(&n))),
+                display_option(&self.uv_index),
+                display_option(&self.precipitation),
+                display_option(&self.precipitation_hours),
+                display_option(&self.precipitation_probability),
+                display_option(&self.rain),
+                display_option(&self.showers),
+                display_option(&self.snowfall),
+                display_option(&self.max_windspeed),
+                display_option(&self.max_windgusts),
+                display_option(&self.wind_direction),
+                display_option(&self.radiation)
             )
         );
     }
@@ -95,11 +712,58 @@ impl From<DailyWeather> for HashMap<String, String>
     }
 }
 
+// looks up a variable in one of the response's unit maps, tidying the api's spelling of a few of them
+fn display_unit(units: &HashMap<String, String>, variable: &str) -> String
+{
+    return match units.get(variable).map(|n| n.as_str())
+    {
+        Some("mp/h") => String::from("mph"),
+        Some("inch") => String::from("in"),
+        Some(unit) => unit.to_string(),
+        None => String::new()
+    };
+}
+
 // container to hold daily forwcasts
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct WeeklyForecast
 {
-    pub days: Vec<DailyWeather>
+    pub days: Vec<DailyWeather>,
+    pub units: HashMap<String, String>,
+    pub utc_offset: Option<FixedOffset>,
+    pub fetched_at: Option<DateTime<Utc>>
+}
+
+impl WeeklyForecast
+{
+    // the unit the api reported a daily variable in, ready to be appended to its values
+    pub fn unit(&self, variable: &str) -> String
+    {
+        return display_unit(&self.units, variable);
+    }
+
+    // the current date at the forecast's location rather than where weatherman is running
+    pub fn today(&self) -> NaiveDate
+    {
+        return match self.utc_offset
+        {
+            Some(offset) => Utc::now().with_timezone(&offset).date_naive(),
+            None => Utc::now().date_naive()
+        };
+    }
+
+    pub fn is_today(&self, day: &DailyWeather) -> bool
+    {
+        return day.date == self.today();
+    }
+
+    // where today is among the days, the first day if it isn't one of them
+    pub fn today_index(&self) -> usize
+    {
+        let today: NaiveDate = self.today();
+
+        return self.days.iter().position(|day| day.date == today).unwrap_or(0);
+    }
 }
 
 impl Display for WeeklyForecast
@@ -108,58 +772,701 @@ impl Display for WeeklyForecast
     {
         for day in self.days.iter()
         {
-            fmt.write_fmt(format_args!("{}\n", day.to_string()))?;
+            fmt.write_fmt(format_args!("{}\n", day))?;
         }
 
         return Ok(());
     }
 }
 
-// for ease of use in converting APIResponse to a weekly forecase
-impl From<APIResponse> for WeeklyForecast
+// everything that can be wrong with an otherwise well formed api response
+#[derive(Debug, PartialEq)]
+pub enum ForecastError
 {
-    fn from(api_response: APIResponse) -> WeeklyForecast
-    {
-        let mut days: Vec<DailyWeather> = Vec::with_capacity(api_response.daily.time.len());
+    // a variable has a different number of values than there are timestamps
+    MismatchedLength { variable: &'static str, expected: usize, found: usize },
+    // a timestamp isn't in the "YYYY-MM-DD" or "YYYY-MM-DDTHH:MM" shape the api documents
+    MalformedTime { variable: &'static str, value: String },
+    // the utc offset is further from utc than any real timezone
+    InvalidOffset(i64)
+}
 
-        for i in 0..api_response.daily.time.len()
+impl Display for ForecastError
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        return match self
         {
-            let sunrise: String = api_response.daily.sunrise[i]
-                .split("T")
-                .map(|n| n.to_string())
-                .collect::< Vec<String> >()[1]
-                .to_string();
+            ForecastError::MismatchedLength { variable, expected, found } => fmt.write_fmt(format_args!("expected {} values for '{}' but got {}", expected, variable, found)),
+            ForecastError::MalformedTime { variable, value } => fmt.write_fmt(format_args!("'{}' is not a valid time for '{}'", value, variable)),
+            ForecastError::InvalidOffset(seconds) => fmt.write_fmt(format_args!("{} seconds is not a valid utc offset", seconds))
+        };
+    }
+}
+
+impl std::error::Error for ForecastError {}
 
-            let sunset: String = api_response.daily.sunset[i]
-                .split("T")
-                .map(|n| n.to_string())
-                .collect::< Vec<String> >()[1]
-                .to_string();
+// makes sure a variable has a value for every timestamp before it is indexed
+fn check_length<T>(variable: &'static str, values: &[T], expected: usize) -> Result<(), ForecastError>
+{
+    if values.len() != expected
+    {
+        return Err(ForecastError::MismatchedLength { variable, expected, found: values.len() });
+    }
+
+    return Ok(());
+}
+
+// same as check_length, but a variable that wasn't sent at all is fine too
+fn check_optional_length<T>(variable: &'static str, values: &[Option<T>], expected: usize) -> Result<(), ForecastError>
+{
+    if values.is_empty()
+    {
+        return Ok(());
+    }
+
+    return check_length(variable, values, expected);
+}
+
+// the value of an optional variable at an index, missing if the variable wasn't sent
+fn optional_value<T: Copy>(values: &[Option<T>], index: usize) -> Option<T>
+{
+    return values.get(index).copied().flatten();
+}
 
+// parses a "YYYY-MM-DD" date
+fn parse_date(variable: &'static str, date: &str) -> Result<NaiveDate, ForecastError>
+{
+    return NaiveDate::parse_from_str(date, "%Y-%m-%d")
+        .map_err(|_| ForecastError::MalformedTime { variable, value: date.to_string() });
+}
+
+// parses a "YYYY-MM-DDTHH:MM" local timestamp and pins it to the location's utc offset
+fn parse_time(variable: &'static str, timestamp: &str, offset: &FixedOffset) -> Result<DateTime<FixedOffset>, ForecastError>
+{
+    return NaiveDateTime::parse_from_str(timestamp, "%Y-%m-%dT%H:%M")
+        .ok()
+        .and_then(|n| n.and_local_timezone(*offset).single())
+        .ok_or(ForecastError::MalformedTime { variable, value: timestamp.to_string() });
+}
+
+// the utc offset every timestamp in the response is given in
+fn parse_offset(api_response: &APIResponse) -> Result<FixedOffset, ForecastError>
+{
+    return i32::try_from(api_response.utc_offset_seconds)
+        .ok()
+        .and_then(FixedOffset::east_opt)
+        .ok_or(ForecastError::InvalidOffset(api_response.utc_offset_seconds));
+}
+
+// for ease of use in converting APIResponse to a weekly forecase, failing if the daily arrays don't line up
+impl TryFrom<APIResponse> for WeeklyForecast
+{
+    type Error = ForecastError;
+
+    fn try_from(api_response: APIResponse) -> Result<WeeklyForecast, ForecastError>
+    {
+        let daily: &APIDaily = &api_response.daily;
+        let len: usize = daily.time.len();
+        let offset: FixedOffset = parse_offset(&api_response)?;
+
+        check_length("temperature_2m_max", &daily.temperature_2m_max, len)?;
+        check_length("temperature_2m_min", &daily.temperature_2m_min, len)?;
+        check_length("sunrise", &daily.sunrise, len)?;
+        check_length("sunset", &daily.sunset, len)?;
+        check_length("precipitation_sum", &daily.precipitation_sum, len)?;
+        check_length("windspeed_10m_max", &daily.windspeed_10m_max, len)?;
+        check_length("weathercode", &daily.weathercode, len)?;
+        check_optional_length("apparent_temperature_max", &daily.apparent_temperature_max, len)?;
+        check_optional_length("apparent_temperature_min", &daily.apparent_temperature_min, len)?;
+        check_optional_length("uv_index_max", &daily.uv_index_max, len)?;
+        check_optional_length("precipitation_hours", &daily.precipitation_hours, len)?;
+        check_optional_length("windgusts_10m_max", &daily.windgusts_10m_max, len)?;
+        check_optional_length("winddirection_10m_dominant", &daily.winddirection_10m_dominant, len)?;
+        check_optional_length("sunshine_duration", &daily.sunshine_duration, len)?;
+        check_optional_length("precipitation_probability_max", &daily.precipitation_probability_max, len)?;
+        check_optional_length("rain_sum", &daily.rain_sum, len)?;
+        check_optional_length("showers_sum", &daily.showers_sum, len)?;
+        check_optional_length("snowfall_sum", &daily.snowfall_sum, len)?;
+        check_optional_length("shortwave_radiation_sum", &daily.shortwave_radiation_sum, len)?;
+
+        let mut days: Vec<DailyWeather> = Vec::with_capacity(len);
+
+        for i in 0..len
+        {
             days.push
             (
                 DailyWeather
                 {
-                    date: api_response.daily.time[i].clone(),
-                    max_temp: api_response.daily.temperature_2m_max[i],
-                    min_temp: api_response.daily.temperature_2m_min[i],
-                    sunrise,
-                    sunset,
-                    precipitation: api_response.daily.precipitation_sum[i],
-                    max_windspeed: api_response.daily.windspeed_10m_max[i]
+                    date: parse_date("time", &daily.time[i])?,
+                    max_temp: daily.temperature_2m_max[i],
+                    min_temp: daily.temperature_2m_min[i],
+                    sunrise: daily.sunrise[i].as_ref().map(|n| parse_time("sunrise", n, &offset)).transpose()?,
+                    sunset: daily.sunset[i].as_ref().map(|n| parse_time("sunset", n, &offset)).transpose()?,
+                    precipitation: daily.precipitation_sum[i],
+                    max_windspeed: daily.windspeed_10m_max[i],
+                    condition: daily.weathercode[i].and_then(WeatherCondition::from_code),
+                    apparent_max_temp: optional_value(&daily.apparent_temperature_max, i),
+                    apparent_min_temp: optional_value(&daily.apparent_temperature_min, i),
+                    uv_index: optional_value(&daily.uv_index_max, i),
+                    precipitation_hours: optional_value(&daily.precipitation_hours, i),
+                    max_windgusts: optional_value(&daily.windgusts_10m_max, i),
+                    wind_direction: optional_value(&daily.winddirection_10m_dominant, i),
+                    // sent in seconds
+                    sunshine: optional_value(&daily.sunshine_duration, i).map(|n| Duration::seconds(n as i64)),
+                    precipitation_probability: optional_value(&daily.precipitation_probability_max, i),
+                    rain: optional_value(&daily.rain_sum, i),
+                    showers: optional_value(&daily.showers_sum, i),
+                    snowfall: optional_value(&daily.snowfall_sum, i),
+                    radiation: optional_value(&daily.shortwave_radiation_sum, i)
                 }
             );
         }
 
-        return WeeklyForecast { days };
+        return Ok(WeeklyForecast { days, units: api_response.daily_units, utc_offset: Some(offset), fetched_at: Some(Utc::now()) });
+    }
+}
+
+// necessary information for each hour's weather
+#[derive(Clone, Debug)]
+// like the daily values, anything the api can send back as null is optional
+pub struct HourlyWeather
+{
+    pub time: DateTime<FixedOffset>,
+    pub temp: Option<f32>,
+    pub apparent_temp: Option<f32>,
+    pub precipitation_probability: Option<f32>,
+    pub windspeed: Option<f32>,
+    pub condition: Option<WeatherCondition>,
+    // degrees the wind blows from
+    pub winddirection: Option<f32>
+}
+
+impl HourlyWeather
+{
+    // the date part of the hour's timestamp, matching DailyWeather's date
+    pub fn date(&self) -> NaiveDate
+    {
+        return self.time.date_naive();
+    }
+
+    // the clock part of the hour's timestamp
+    pub fn hour(&self) -> NaiveTime
+    {
+        return self.time.time();
     }
 }
 
-// generates reqwest request to weather api and returns the response
-pub async fn generate_request() -> Result<APIResponse, reqwest::Error>
+// container to hold every hour the api returned
+#[derive(Debug, Default)]
+pub struct HourlyForecast
 {
-    return Ok(reqwest::get("https://api.open-meteo.com/v1/forecast?latitude=42.64&longitude=-82.96&daily=temperature_2m_max,temperature_2m_min,sunrise,sunset,precipitation_sum,windspeed_10m_max&temperature_unit=fahrenheit&windspeed_unit=mph&precipitation_unit=inch&timezone=America%2FNew_York")
-        .await?
-        .json::<APIResponse>()
-        .await?);
+    pub hours: Vec<HourlyWeather>,
+    pub units: HashMap<String, String>
+}
+
+impl HourlyForecast
+{
+    // the unit the api reported an hourly variable in, ready to be appended to its values
+    pub fn unit(&self, variable: &str) -> String
+    {
+        return display_unit(&self.units, variable);
+    }
+
+    // the hours falling on the given date, in order
+    pub fn for_date(&self, date: NaiveDate) -> Vec<HourlyWeather>
+    {
+        return self.hours.iter()
+            .filter(|hour| hour.date() == date)
+            .cloned()
+            .collect();
+    }
+}
+
+// hourly data is read out of the response before it is consumed by the weekly forecast, failing if the hourly arrays don't line up
+impl TryFrom<&APIResponse> for HourlyForecast
+{
+    type Error = ForecastError;
+
+    fn try_from(api_response: &APIResponse) -> Result<HourlyForecast, ForecastError>
+    {
+        let hourly: &APIHourly = &api_response.hourly;
+        let len: usize = hourly.time.len();
+        let offset: FixedOffset = parse_offset(api_response)?;
+
+        check_length("temperature_2m", &hourly.temperature_2m, len)?;
+        check_length("apparent_temperature", &hourly.apparent_temperature, len)?;
+        check_length("precipitation_probability", &hourly.precipitation_probability, len)?;
+        check_length("windspeed_10m", &hourly.windspeed_10m, len)?;
+        check_length("weathercode", &hourly.weathercode, len)?;
+        check_optional_length("winddirection_10m", &hourly.winddirection_10m, len)?;
+
+        let mut hours: Vec<HourlyWeather> = Vec::with_capacity(len);
+
+        for i in 0..len
+        {
+            hours.push
+            (
+                HourlyWeather
+                {
+                    time: parse_time("time", &hourly.time[i], &offset)?,
+                    temp: hourly.temperature_2m[i],
+                    apparent_temp: hourly.apparent_temperature[i],
+                    precipitation_probability: hourly.precipitation_probability[i],
+                    windspeed: hourly.windspeed_10m[i],
+                    condition: hourly.weathercode[i].and_then(WeatherCondition::from_code),
+                    winddirection: optional_value(&hourly.winddirection_10m, i)
+                }
+            );
+        }
+
+        return Ok(HourlyForecast { hours, units: api_response.hourly_units.clone() });
+    }
+}
+
+// base url of the forecast api
+const FORECAST_URL: &str = "https://api.open-meteo.com/v1/forecast";
+
+// base url of the geocoding api
+const GEOCODING_URL: &str = "https://geocoding-api.open-meteo.com/v1/search";
+
+// how many candidates to ask the geocoding api for
+const GEOCODING_COUNT: usize = 10;
+
+// how long a request can take before giving up on it, so a stalled connection ends in an error instead of a hang
+const REQUEST_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(20);
+
+// everything that can go wrong while talking to the weather api
+#[derive(Debug)]
+pub enum WeatherError
+{
+    // the request never got a response: offline, dns failure, timeout...
+    Transport(reqwest::Error),
+    // the api answered with a non-success status and no explanation
+    Status(reqwest::StatusCode),
+    // the api answered with its own '{"error":true,"reason":...}' body
+    Api(String),
+    // the response wasn't in the shape we expected
+    Decode(serde_json::Error),
+    // the response was well formed but its contents didn't add up
+    Invalid(ForecastError)
+}
+
+impl Display for WeatherError
+{
+    fn fmt(&self, fmt: &mut Formatter) -> Result<(), fmt::Error>
+    {
+        return match self
+        {
+            WeatherError::Transport(e) => fmt.write_fmt(format_args!("couldn't reach the weather service: {}", e)),
+            WeatherError::Status(status) => fmt.write_fmt(format_args!("the weather service responded with {}", status)),
+            WeatherError::Api(reason) => fmt.write_fmt(format_args!("the weather service rejected the request: {}", reason)),
+            WeatherError::Decode(e) => fmt.write_fmt(format_args!("couldn't understand the weather service's response: {}", e)),
+            WeatherError::Invalid(e) => fmt.write_fmt(format_args!("the weather service sent an inconsistent forecast: {}", e))
+        };
+    }
+}
+
+impl std::error::Error for WeatherError
+{
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)>
+    {
+        return match self
+        {
+            WeatherError::Transport(e) => Some(e),
+            WeatherError::Decode(e) => Some(e),
+            WeatherError::Invalid(e) => Some(e),
+            _ => None
+        };
+    }
+}
+
+impl From<reqwest::Error> for WeatherError
+{
+    fn from(e: reqwest::Error) -> WeatherError
+    {
+        return WeatherError::Transport(e);
+    }
+}
+
+impl From<ForecastError> for WeatherError
+{
+    fn from(e: ForecastError) -> WeatherError
+    {
+        return WeatherError::Invalid(e);
+    }
+}
+
+impl From<serde_json::Error> for WeatherError
+{
+    fn from(e: serde_json::Error) -> WeatherError
+    {
+        return WeatherError::Decode(e);
+    }
+}
+
+// format of the body the apis send alongside a failed request
+#[derive(Deserialize, Debug)]
+struct APIError
+{
+    error: bool,
+    reason: String
+}
+
+// sends a get request and decodes its json body, telling apart every way it can fail
+async fn get_json<T: serde::de::DeserializeOwned>(url: &str, params: &[(&str, String)]) -> Result<T, WeatherError>
+{
+    let response: reqwest::Response = reqwest::Client::builder()
+        .timeout(REQUEST_TIMEOUT)
+        .build()?
+        .get(url)
+        .query(params)
+        .send()
+        .await?;
+
+    let status: reqwest::StatusCode = response.status();
+    let body: String = response.text().await?;
+
+    if let Ok(APIError { error: true, reason }) = serde_json::from_str::<APIError>(&body)
+    {
+        return Err(WeatherError::Api(reason));
+    }
+
+    if !status.is_success()
+    {
+        return Err(WeatherError::Status(status));
+    }
+
+    return Ok(serde_json::from_str::<T>(&body)?);
+}
+
+// generates reqwest request to weather api for the given location and units and returns the response
+pub async fn generate_request(location: &Location, units: &Units, horizon: &Horizon) -> Result<APIResponse, WeatherError>
+{
+    let mut params: Vec<(&str, String)> = vec!
+    [
+        ("latitude", location.latitude.to_string()),
+        ("longitude", location.longitude.to_string()),
+        ("daily", "weathercode,temperature_2m_max,temperature_2m_min,apparent_temperature_max,apparent_temperature_min,sunrise,sunset,sunshine_duration,uv_index_max,shortwave_radiation_sum,precipitation_sum,precipitation_probability_max,precipitation_hours,rain_sum,showers_sum,snowfall_sum,windspeed_10m_max,windgusts_10m_max,winddirection_10m_dominant".to_string()),
+        ("hourly", "temperature_2m,apparent_temperature,precipitation_probability,windspeed_10m,winddirection_10m,weathercode".to_string()),
+        ("current_weather", "true".to_string()),
+        ("temperature_unit", units.temperature.query_value().to_string()),
+        ("windspeed_unit", units.wind.query_value().to_string()),
+        ("precipitation_unit", units.precipitation.query_value().to_string()),
+        ("forecast_days", horizon.forecast_days.to_string()),
+        ("past_days", horizon.past_days.to_string()),
+        ("timezone", location.timezone.clone())
+    ];
+
+    if let Some(elevation) = location.elevation
+    {
+        params.push(("elevation", elevation.to_string()));
+    }
+
+    return get_json::<APIResponse>(FORECAST_URL, &params).await;
+}
+
+// conditions right now, as reported alongside the forecast
+#[derive(Clone, Debug)]
+pub struct CurrentWeather
+{
+    pub time: DateTime<FixedOffset>,
+    pub temp: f32,
+    pub windspeed: f32,
+    pub winddirection: f32,
+    pub condition: Option<WeatherCondition>,
+    pub is_day: bool
+}
+
+// reads the current conditions out of the response, if the api sent any
+fn parse_current(api_response: &APIResponse) -> Result<Option<CurrentWeather>, ForecastError>
+{
+    let current: &APICurrentWeather = match &api_response.current_weather
+    {
+        Some(current) => current,
+        None => return Ok(None)
+    };
+
+    return Ok
+    (
+        Some
+        (
+            CurrentWeather
+            {
+                time: parse_time("current_weather.time", &current.time, &parse_offset(api_response)?)?,
+                temp: current.temperature,
+                windspeed: current.windspeed,
+                winddirection: current.winddirection,
+                condition: WeatherCondition::from_code(current.weathercode),
+                is_day: current.is_day != 0
+            }
+        )
+    );
+}
+
+// everything a single request to the api yields
+#[derive(Debug, Default)]
+pub struct Forecast
+{
+    pub current: Option<CurrentWeather>,
+    pub hourly: HourlyForecast,
+    pub weekly: WeeklyForecast
+}
+
+impl TryFrom<APIResponse> for Forecast
+{
+    type Error = ForecastError;
+
+    fn try_from(api_response: APIResponse) -> Result<Forecast, ForecastError>
+    {
+        let current: Option<CurrentWeather> = parse_current(&api_response)?;
+        let hourly: HourlyForecast = HourlyForecast::try_from(&api_response)?;
+
+        return Ok(Forecast { current, hourly, weekly: WeeklyForecast::try_from(api_response)? });
+    }
+}
+
+// requests the forecast and converts it into its current, hourly and daily forms
+pub async fn fetch_forecast(location: &Location, units: &Units, horizon: &Horizon) -> Result<Forecast, WeatherError>
+{
+    return Ok(Forecast::try_from(generate_request(location, units, horizon).await?)?);
+}
+
+// looks up a place name or postal code and returns the candidate locations, best match first
+pub async fn search_locations(query: &str) -> Result<Vec<GeocodingResult>, WeatherError>
+{
+    let params: [(&str, String); 4] =
+    [
+        ("name", query.trim().to_string()),
+        ("count", GEOCODING_COUNT.to_string()),
+        ("language", "en".to_string()),
+        ("format", "json".to_string())
+    ];
+
+    return Ok(get_json::<GeocodingResponse>(GEOCODING_URL, &params).await?.results);
+}
+
+#[cfg(test)]
+mod tests
+{
+    use super::*;
+    use serde_json::json;
+
+    // builds a response around the given daily block, with an empty hourly block
+    fn response(daily: serde_json::Value) -> APIResponse
+    {
+        return serde_json::from_value
+        (
+            json!
+            ({
+                "latitude": 42.64,
+                "longitude": -82.96,
+                "generationtime_ms": 0.5,
+                "utc_offset_seconds": -18000,
+                "timezone": "America/New_York",
+                "timezone_abbreviation": "EST",
+                "elevation": 180.0,
+                "daily_units": { "temperature_2m_max": "°F" },
+                "daily": daily,
+                "hourly_units": {},
+                "hourly":
+                {
+                    "time": [],
+                    "temperature_2m": [],
+                    "apparent_temperature": [],
+                    "precipitation_probability": [],
+                    "windspeed_10m": [],
+                    "weathercode": []
+                }
+            })
+        ).expect("test response should deserialize");
+    }
+
+    #[test]
+    fn converts_well_formed_days()
+    {
+        let forecast: WeeklyForecast = WeeklyForecast::try_from
+        (
+            response
+            (
+                json!
+                ({
+                    "time": ["2023-01-31", "2023-02-01"],
+                    "temperature_2m_max": [35.2, 40.1],
+                    "temperature_2m_min": [20.0, 28.4],
+                    "sunrise": ["2023-01-31T07:45", "2023-02-01T07:44"],
+                    "sunset": ["2023-01-31T17:33", "2023-02-01T17:34"],
+                    "precipitation_sum": [0.0, 0.12],
+                    "windspeed_10m_max": [10.5, 12.3],
+                    "weathercode": [0, 73]
+                })
+            )
+        ).expect("well formed response should convert");
+
+        assert_eq!(forecast.days.len(), 2);
+        assert_eq!(forecast.days[1].max_temp, Some(40.1));
+        assert_eq!(forecast.days[0].sunrise.map(|n| n.time()), NaiveTime::from_hms_opt(7, 45, 0));
+        assert_eq!(forecast.days[0].sunrise.map(|n| n.offset().local_minus_utc()), Some(-18000));
+        assert_eq!(forecast.days[0].daylight(), Some(Duration::minutes(588)));
+        assert_eq!(forecast.unit("temperature_2m_max"), "°F");
+        assert_eq!(forecast.days[1].condition, Some(WeatherCondition::Snow));
+    }
+
+    #[test]
+    fn rejects_ragged_arrays()
+    {
+        let result: Result<WeeklyForecast, ForecastError> = WeeklyForecast::try_from
+        (
+            response
+            (
+                json!
+                ({
+                    "time": ["2023-01-31", "2023-02-01"],
+                    "temperature_2m_max": [35.2, 40.1],
+                    "temperature_2m_min": [20.0],
+                    "sunrise": ["2023-01-31T07:45", "2023-02-01T07:44"],
+                    "sunset": ["2023-01-31T17:33", "2023-02-01T17:34"],
+                    "precipitation_sum": [0.0, 0.12],
+                    "windspeed_10m_max": [10.5, 12.3],
+                    "weathercode": [0, 73]
+                })
+            )
+        );
+
+        assert_eq!(result.unwrap_err(), ForecastError::MismatchedLength { variable: "temperature_2m_min", expected: 2, found: 1 });
+    }
+
+    #[test]
+    fn keeps_missing_sunrise_and_sunset_as_none()
+    {
+        let forecast: WeeklyForecast = WeeklyForecast::try_from
+        (
+            response
+            (
+                json!
+                ({
+                    "time": ["2023-06-21"],
+                    "temperature_2m_max": [null],
+                    "temperature_2m_min": [30.0],
+                    "sunrise": [null],
+                    "sunset": [null],
+                    "precipitation_sum": [0.0],
+                    "windspeed_10m_max": [5.0],
+                    "weathercode": [null]
+                })
+            )
+        ).expect("null values should convert");
+
+        assert_eq!(forecast.days[0].sunrise, None);
+        assert_eq!(forecast.days[0].sunset, None);
+        assert_eq!(forecast.days[0].max_temp, None);
+        assert_eq!(forecast.days[0].daylight(), None);
+        assert_eq!(forecast.days[0].condition, None);
+        assert!(forecast.days[0].to_string().contains("sunrise: --"));
+    }
+
+    #[test]
+    fn keeps_missing_hourly_values_as_none()
+    {
+        let mut api_response: APIResponse = response(json!({ "time": [], "temperature_2m_max": [], "temperature_2m_min": [], "sunrise": [], "sunset": [], "precipitation_sum": [], "windspeed_10m_max": [], "weathercode": [] }));
+        api_response.hourly = serde_json::from_value
+        (
+            json!
+            ({
+                "time": ["2023-01-31T00:00", "2023-01-31T01:00"],
+                "temperature_2m": [30.5, null],
+                "apparent_temperature": [null, 25.0],
+                "precipitation_probability": [10.0, null],
+                "windspeed_10m": [null, 4.2],
+                "weathercode": [3, null]
+            })
+        ).expect("hourly block with nulls should deserialize");
+
+        let hourly: HourlyForecast = HourlyForecast::try_from(&api_response).expect("null hours should convert");
+
+        assert_eq!(hourly.hours[0].temp, Some(30.5));
+        assert_eq!(hourly.hours[1].temp, None);
+        assert_eq!(hourly.hours[0].apparent_temp, None);
+        assert_eq!(hourly.hours[0].windspeed, None);
+        assert_eq!(hourly.hours[1].windspeed, Some(4.2));
+    }
+
+    #[test]
+    fn names_wind_directions_and_forces()
+    {
+        assert_eq!(compass_point(0.0), "N");
+        assert_eq!(compass_point(359.0), "N");
+        assert_eq!(compass_point(22.5), "NNE");
+        assert_eq!(compass_point(225.0), "SW");
+        assert_eq!(wind_arrow(90.0), "←");
+        assert_eq!(wind_arrow(-45.0), "↘");
+        assert_eq!(beaufort_force(0.0), 0);
+        assert_eq!(beaufort_force(WindUnit::MilesPerHour.to_meters_per_second(20.0)), 5);
+        assert_eq!(beaufort_force(40.0), 12);
+        assert_eq!(WindUnit::from_suffix("kn"), Some(WindUnit::Knots));
+    }
+
+    #[test]
+    fn reads_detail_variables_only_when_sent()
+    {
+        let forecast: WeeklyForecast = WeeklyForecast::try_from
+        (
+            response
+            (
+                json!
+                ({
+                    "time": ["2023-01-31", "2023-02-01"],
+                    "temperature_2m_max": [35.2, 40.1],
+                    "temperature_2m_min": [20.0, 28.4],
+                    "sunrise": ["2023-01-31T07:45", "2023-02-01T07:44"],
+                    "sunset": ["2023-01-31T17:33", "2023-02-01T17:34"],
+                    "precipitation_sum": [0.0, 0.12],
+                    "windspeed_10m_max": [10.5, 12.3],
+                    "weathercode": [0, 73],
+                    "uv_index_max": [2.5, null],
+                    "sunshine_duration": [3600.0, 0.0]
+                })
+            )
+        ).expect("missing detail variables should convert");
+
+        assert_eq!(forecast.days[0].uv_index, Some(2.5));
+        assert_eq!(forecast.days[1].uv_index, None);
+        assert_eq!(forecast.days[0].sunshine, Some(Duration::hours(1)));
+        assert_eq!(forecast.days[0].max_windgusts, None);
+    }
+
+    #[test]
+    fn rejects_malformed_sunrise()
+    {
+        let result: Result<WeeklyForecast, ForecastError> = WeeklyForecast::try_from
+        (
+            response
+            (
+                json!
+                ({
+                    "time": ["2023-01-31"],
+                    "temperature_2m_max": [35.2],
+                    "temperature_2m_min": [20.0],
+                    "sunrise": ["07:45"],
+                    "sunset": ["2023-01-31T17:33"],
+                    "precipitation_sum": [0.0],
+                    "windspeed_10m_max": [10.5],
+                    "weathercode": [3]
+                })
+            )
+        );
+
+        assert_eq!(result.unwrap_err(), ForecastError::MalformedTime { variable: "sunrise", value: String::from("07:45") });
+    }
+
+    #[test]
+    fn maps_wmo_codes_to_conditions()
+    {
+        assert_eq!(WeatherCondition::from_code(0), Some(WeatherCondition::Clear));
+        assert_eq!(WeatherCondition::from_code(48), Some(WeatherCondition::Fog));
+        assert_eq!(WeatherCondition::from_code(66), Some(WeatherCondition::FreezingRain));
+        assert_eq!(WeatherCondition::from_code(81), Some(WeatherCondition::Showers));
+        assert_eq!(WeatherCondition::from_code(99), Some(WeatherCondition::Hail));
+        assert_eq!(WeatherCondition::from_code(4), None);
+    }
 }